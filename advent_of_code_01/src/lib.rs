@@ -0,0 +1,110 @@
+use anyhow::{bail, Result};
+use common::{parse::ParseError, Solution};
+
+/// Calibration document of the trebuchet
+pub struct CalibrationDocument {
+    lines: Vec<String>,
+}
+
+fn task1(lines: &[String]) -> Result<u32> {
+    let mut sum = 0;
+    for (index, line) in lines.iter().enumerate() {
+        let mut digits = line.chars().filter_map(|c| c.to_digit(10));
+        let Some(first) = digits.next() else {
+            bail!("line {} has no numeric digit", index + 1);
+        };
+        sum += 10 * first + digits.next_back().unwrap_or(first);
+    }
+    Ok(sum)
+}
+
+fn task2(lines: &[String]) -> u32 {
+    lines
+        .iter()
+        .map(|line| {
+            let mut digits = spelled_digits(line);
+            // parsing made sure there is a digit on each line
+            let first = digits.next().unwrap();
+            10 * first + digits.next_back().unwrap_or(first)
+        })
+        .sum()
+}
+
+/// Digits of the line, spelled out or not, in order
+fn spelled_digits(line: &str) -> impl DoubleEndedIterator<Item = u32> + '_ {
+    line.char_indices()
+        .filter_map(|(i, _)| is_digit(&line[i..]))
+}
+
+fn is_digit(str: &str) -> Option<u32> {
+    match str {
+        _ if str.starts_with("one") | str.starts_with('1') => Some(1),
+        _ if str.starts_with("two") | str.starts_with('2') => Some(2),
+        _ if str.starts_with("three") | str.starts_with('3') => Some(3),
+        _ if str.starts_with("four") | str.starts_with('4') => Some(4),
+        _ if str.starts_with("five") | str.starts_with('5') => Some(5),
+        _ if str.starts_with("six") | str.starts_with('6') => Some(6),
+        _ if str.starts_with("seven") | str.starts_with('7') => Some(7),
+        _ if str.starts_with("eight") | str.starts_with('8') => Some(8),
+        _ if str.starts_with("nine") | str.starts_with('9') => Some(9),
+        _ => None,
+    }
+}
+
+impl Solution for CalibrationDocument {
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self> {
+        let mut lines = Vec::new();
+        for line in input.lines() {
+            if spelled_digits(line).next().is_none() {
+                return Err(ParseError::at(input, line, "expected a digit").into());
+            }
+            lines.push(String::from(line));
+        }
+        Ok(Self { lines })
+    }
+
+    fn part1(&self) -> Result<u32> {
+        task1(&self.lines)
+    }
+
+    fn part2(&self) -> Result<u32> {
+        Ok(task2(&self.lines))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_1() -> Result<()> {
+        let document = CalibrationDocument::parse(include_str!("../input_small_1.txt"))?;
+        assert_eq!(document.part1()?, 142);
+        assert_eq!(document.part2()?, 142);
+        Ok(())
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        let document = CalibrationDocument::parse(include_str!("../input_small_2.txt"))?;
+        assert_eq!(document.part2()?, 281);
+        Ok(())
+    }
+
+    #[test]
+    fn missing_digit() -> Result<()> {
+        let Err(error) = CalibrationDocument::parse("1abc2\npqrstu\n") else {
+            panic!("the second line has no digit");
+        };
+        let error = error.downcast::<ParseError>().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+
+        let document = CalibrationDocument::parse("1abc2\nseven\n")?;
+        assert!(document.part1().is_err());
+        assert_eq!(document.part2()?, 89);
+        Ok(())
+    }
+}
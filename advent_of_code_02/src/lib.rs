@@ -0,0 +1,111 @@
+use anyhow::Result;
+use common::{
+    parse::{self, ParseError},
+    Solution,
+};
+
+const COLORS: [&str; 3] = ["red", "blue", "green"];
+
+/// Games played with the elf, with the most cubes of each color revealed per game
+pub struct Games {
+    games: Vec<Game>,
+}
+
+fn task1(games: &[Game]) -> u32 {
+    let bag = [12_u32, 14, 13];
+
+    games
+        .iter()
+        .filter(|game| {
+            game.colors
+                .iter()
+                .zip(bag.iter())
+                .all(|(count, max)| count <= max)
+        })
+        .map(|game| game.id)
+        .sum()
+}
+
+struct Game {
+    id: u32,
+    colors: [u32; 3],
+}
+
+impl Game {
+    fn new(id: u32) -> Self {
+        Self {
+            id,
+            colors: [0, 0, 0],
+        }
+    }
+
+    fn add(&mut self, input: &str, entry: &str) -> Result<(), ParseError> {
+        let (count, color) = parse::split_once(input, entry.trim(), " ")?;
+        let i = COLORS
+            .iter()
+            .position(|c| *c == color)
+            .ok_or_else(|| ParseError::at(input, color, "unknown color"))?;
+        let count = parse::parse::<u32>(input, count)?;
+        self.colors[i] = self.colors[i].max(count);
+        Ok(())
+    }
+
+    fn power(&self) -> u32 {
+        self.colors.iter().product()
+    }
+}
+
+fn task2(games: &[Game]) -> u32 {
+    games.iter().map(Game::power).sum()
+}
+
+fn parse_game(input: &str, line: &str) -> Result<Game, ParseError> {
+    let (game_id, reveals) = parse::split_once(input, line, ":")?;
+
+    // get game id
+    let game_id = game_id
+        .strip_prefix("Game ")
+        .ok_or_else(|| ParseError::at(input, game_id, "expected `Game <id>`"))?;
+    let mut game = Game::new(parse::parse(input, game_id)?);
+
+    for reveal in reveals.split(';') {
+        for cube in reveal.split(',') {
+            game.add(input, cube)?;
+        }
+    }
+    Ok(game)
+}
+
+impl Solution for Games {
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self> {
+        let games = input
+            .lines()
+            .map(|line| parse_game(input, line))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { games })
+    }
+
+    fn part1(&self) -> Result<u32> {
+        Ok(task1(&self.games))
+    }
+
+    fn part2(&self) -> Result<u32> {
+        Ok(task2(&self.games))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<()> {
+        let games = Games::parse(include_str!("../input_small.txt"))?;
+        assert_eq!(games.part1()?, 8);
+        assert_eq!(games.part2()?, 2286);
+        Ok(())
+    }
+}
@@ -0,0 +1,220 @@
+use anyhow::Result;
+use common::{geometry::Position, grid::Grid, Solution};
+
+/// Engine schematic
+pub struct Schematic(Grid<char>);
+
+impl Schematic {
+    fn height(&self) -> usize {
+        self.0.height()
+    }
+
+    fn width(&self) -> usize {
+        self.0.width()
+    }
+
+    fn get(&self, pos: Position) -> char {
+        self.0[pos]
+    }
+
+    fn is_digit(&self, pos: Position) -> bool {
+        self.get(pos).is_ascii_digit()
+    }
+
+    fn is_gear(&self, pos: Position) -> bool {
+        self.get(pos) == '*'
+    }
+
+    fn is_symbol(&self, pos: Position) -> bool {
+        self.get(pos) != '.' && !self.is_digit(pos)
+    }
+}
+
+struct PartNumber<'a> {
+    grid: &'a Schematic,
+    start: Position,
+    length: usize,
+    number: u32,
+}
+
+impl<'a> PartNumber<'a> {
+    fn new(grid: &'a Schematic, start: Position) -> Self {
+        let length = (start.x..grid.width())
+            .map(|x| Position::new(x, start.y))
+            .position(|pos| !grid.is_digit(pos))
+            .unwrap_or(grid.width() - start.x);
+
+        let number = grid.0.row(start.y)[start.x..start.x + length]
+            .iter()
+            .collect::<String>()
+            .parse::<u32>()
+            .unwrap();
+
+        Self {
+            grid,
+            start,
+            length,
+            number,
+        }
+    }
+
+    fn end(&self) -> Position {
+        Position::new(self.start.x + self.length - 1, self.start.y)
+    }
+
+    fn is_part(&self) -> bool {
+        // columns from left of the number to right of it
+        let columns =
+            self.start.x.saturating_sub(1)..=(self.end().x + 1).min(self.grid.width() - 1);
+
+        let mut is_symbol = false;
+        // check row above
+        if self.start.y > 0 {
+            is_symbol = columns
+                .clone()
+                .map(|x| Position::new(x, self.start.y - 1))
+                .any(|pos| self.grid.is_symbol(pos));
+        }
+        // check row below
+        if !is_symbol && self.start.y + 1 < self.grid.height() {
+            is_symbol = columns
+                .map(|x| Position::new(x, self.start.y + 1))
+                .any(|pos| self.grid.is_symbol(pos));
+        }
+        // check left
+        if !is_symbol && self.start.x > 0 {
+            is_symbol = self
+                .grid
+                .is_symbol(Position::new(self.start.x - 1, self.start.y));
+        }
+        // check right
+        if !is_symbol && self.start.x + self.length < self.grid.width() {
+            is_symbol = self
+                .grid
+                .is_symbol(Position::new(self.start.x + self.length, self.start.y));
+        }
+        is_symbol
+    }
+}
+
+struct Gear {
+    pos: Position,
+    parts: Vec<u32>,
+}
+
+impl Gear {
+    fn new(pos: Position) -> Self {
+        Self {
+            pos,
+            parts: Vec::new(),
+        }
+    }
+
+    fn next_to(&mut self, part: &PartNumber) {
+        if part.start.x.saturating_sub(1) <= self.pos.x
+            && part.end().x + 1 >= self.pos.x
+            && part.start.y.saturating_sub(1) <= self.pos.y
+            && part.end().y + 1 >= self.pos.y
+        {
+            self.parts.push(part.number);
+        }
+    }
+
+    fn gear_ratio(&self) -> u32 {
+        if self.parts.len() == 2 {
+            self.parts.iter().product()
+        } else {
+            0
+        }
+    }
+}
+
+fn task1(grid: &Schematic) -> u32 {
+    let mut parts = 0;
+    for i in 0..grid.height() {
+        let mut j = 0;
+        while j < grid.width() {
+            j += if grid.is_digit(Position::new(j, i)) {
+                let part = PartNumber::new(grid, Position::new(j, i));
+                if part.is_part() {
+                    parts += part.number;
+                }
+                part.length
+            } else {
+                1
+            }
+        }
+    }
+    parts
+}
+
+fn task2(grid: &Schematic) -> u32 {
+    // iterate over grid and find gears
+    let mut gears = Vec::new();
+    for i in 0..grid.height() {
+        for j in 0..grid.width() {
+            if grid.is_gear(Position::new(j, i)) {
+                gears.push(Gear::new(Position::new(j, i)));
+            }
+        }
+    }
+
+    // iterate over grid and create parts
+    let mut parts = Vec::new();
+    for i in 0..grid.height() {
+        let mut j = 0;
+        while j < grid.width() {
+            if grid.is_digit(Position::new(j, i)) {
+                let part = PartNumber::new(grid, Position::new(j, i));
+                j += part.length;
+                parts.push(part);
+            } else {
+                j += 1;
+            }
+        }
+    }
+
+    // chech if parts are next to gears
+    for gear in &mut gears {
+        for part in &parts {
+            gear.next_to(part);
+        }
+    }
+
+    // count gear ratio
+    let mut sum = 0;
+    for gear in &gears {
+        sum += gear.gear_ratio();
+    }
+    sum
+}
+
+impl Solution for Schematic {
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self(Grid::parse(input)?))
+    }
+
+    fn part1(&self) -> Result<u32> {
+        Ok(task1(self))
+    }
+
+    fn part2(&self) -> Result<u32> {
+        Ok(task2(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<()> {
+        let schematic = Schematic::parse(include_str!("../input_small.txt"))?;
+        assert_eq!(schematic.part1()?, 4361);
+        assert_eq!(schematic.part2()?, 467835);
+        Ok(())
+    }
+}
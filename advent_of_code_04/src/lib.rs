@@ -0,0 +1,86 @@
+use anyhow::Result;
+use common::{
+    parse::{self, ParseError},
+    Solution,
+};
+
+/// Scratchcards, counted by the numbers matching their winning numbers
+pub struct Scratchcards(Vec<u32>);
+
+fn task1(winnings: &[u32]) -> u32 {
+    winnings.iter().fold(0, |acc, count| {
+        acc + if *count == 0 { 0 } else { 2_u32.pow(count - 1) }
+    })
+}
+
+fn task2(winnings: &[u32]) -> u32 {
+    let mut copies = vec![1; winnings.len()];
+    for (i, count) in winnings.iter().enumerate() {
+        for j in 0..*count {
+            let j = j as usize;
+            copies[i + j + 1] += copies[i];
+        }
+    }
+    copies.iter().sum()
+}
+
+fn get_winning_cards(input: &str, line: &str) -> Result<u32, ParseError> {
+    let (_, card) = parse::split_once(input, line, ":")?;
+    let (winning, current) = parse::split_once(input, card, "|")?;
+
+    let winning = winning.split_whitespace().collect::<Vec<_>>();
+    Ok(current
+        .split_whitespace()
+        .filter(|c| winning.contains(c))
+        .count() as u32)
+}
+
+impl Solution for Scratchcards {
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self> {
+        let lines = input.lines().collect::<Vec<_>>();
+        let mut cards = Vec::new();
+        for (index, line) in lines.iter().enumerate() {
+            let count = get_winning_cards(input, line)?;
+            // copies are only won of the cards below the card
+            if index + count as usize >= lines.len() {
+                let message = "wins copies of cards past the last one";
+                return Err(ParseError::at(input, line, message).into());
+            }
+            cards.push(count);
+        }
+        Ok(Self(cards))
+    }
+
+    fn part1(&self) -> Result<u32> {
+        Ok(task1(&self.0))
+    }
+
+    fn part2(&self) -> Result<u32> {
+        Ok(task2(&self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<()> {
+        let cards = Scratchcards::parse(include_str!("../input_small.txt"))?;
+        assert_eq!(cards.part1()?, 13);
+        assert_eq!(cards.part2()?, 30);
+        Ok(())
+    }
+
+    #[test]
+    fn copies_past_the_last_card() {
+        let Err(error) = Scratchcards::parse("Card 1: 41 48 | 41 48\n") else {
+            panic!("the card wins copies of missing cards");
+        };
+        let error = error.downcast::<ParseError>().unwrap();
+        assert_eq!((error.line, error.column), (1, 1));
+    }
+}
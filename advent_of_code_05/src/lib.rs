@@ -0,0 +1,255 @@
+use anyhow::Result;
+use common::{
+    parse::{self, ParseError},
+    Solution,
+};
+
+#[derive(Debug)]
+struct MappingEntry {
+    dst: u64,
+    src: u64,
+    range: u64,
+}
+
+impl MappingEntry {
+    fn new(input: &str, line: &str) -> Result<Self, ParseError> {
+        let values = line
+            .split_whitespace()
+            .map(|v| parse::parse::<u64>(input, v))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match values[..] {
+            [dst, src, range] => Ok(MappingEntry { dst, src, range }),
+            _ => Err(ParseError::at(
+                input,
+                line,
+                "expected destination, source and length",
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Map {
+    entries: Vec<MappingEntry>,
+}
+
+impl Map {
+    fn map(&self, value: u64) -> u64 {
+        for entry in self.entries.iter() {
+            if entry.src <= value && value < entry.src + entry.range {
+                return value - entry.src + entry.dst;
+            }
+        }
+        value
+    }
+
+    fn map_range(&self, seed: &SeedRange) -> Vec<SeedRange> {
+        let mut seeds = vec![*seed; 1];
+        for entry in self.entries.iter() {
+            let mut next = Vec::new();
+            for seed in seeds {
+                if seed.start < entry.src && seed.end > entry.src + entry.range {
+                    next.push(SeedRange {
+                        start: seed.start,
+                        end: entry.src - 1,
+                    });
+                    next.push(SeedRange {
+                        start: entry.src,
+                        end: entry.src + entry.range - 1,
+                    });
+                    next.push(SeedRange {
+                        start: entry.src + entry.range,
+                        end: seed.end,
+                    });
+                } else if seed.start < entry.src && seed.end > entry.src {
+                    next.push(SeedRange {
+                        start: seed.start,
+                        end: entry.src - 1,
+                    });
+                    next.push(SeedRange {
+                        start: entry.src,
+                        end: seed.end,
+                    });
+                } else if seed.start > entry.src
+                    && seed.start < entry.src + entry.range
+                    && seed.end > entry.src + entry.range
+                {
+                    next.push(SeedRange {
+                        start: seed.start,
+                        end: entry.src + entry.range - 1,
+                    });
+                    next.push(SeedRange {
+                        start: entry.src + entry.range,
+                        end: seed.end,
+                    });
+                } else {
+                    next.push(seed);
+                }
+            }
+
+            seeds = next;
+        }
+
+        let mut mapped_seeds = Vec::new();
+        for seed in &seeds {
+            let mut mapped_seed = *seed;
+            for entry in self.entries.iter() {
+                if entry.src <= seed.start && seed.end < entry.src + entry.range {
+                    mapped_seed = SeedRange {
+                        start: seed.start - entry.src + entry.dst,
+                        end: seed.end - entry.src + entry.dst,
+                    };
+                }
+            }
+
+            mapped_seeds.push(mapped_seed);
+        }
+
+        mapped_seeds
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SeedRange {
+    start: u64,
+    end: u64,
+}
+
+/// Seeds to plant and the maps from seeds to locations
+pub struct Almanac {
+    seeds: Vec<u64>,
+    maps: Vec<Map>,
+}
+
+fn task1(almanac: &Almanac) -> u64 {
+    almanac
+        .seeds
+        .iter()
+        .map(|seed| almanac.maps.iter().fold(*seed, |s, m| m.map(s)))
+        .min()
+        .unwrap_or(u64::MAX)
+}
+
+fn task2(almanac: &Almanac) -> u64 {
+    let seeds = almanac
+        .seeds
+        .chunks(2)
+        .map(|range| SeedRange {
+            start: range[0],
+            end: range[0] + range[1] - 1,
+        })
+        .collect::<Vec<_>>();
+
+    let mut min = u64::MAX;
+    for seed in &seeds {
+        let mut mapped_seeds = vec![*seed; 1];
+
+        for map in &almanac.maps {
+            let mut next = Vec::new();
+            for mapped_seed in &mapped_seeds {
+                let mut mapped = map.map_range(mapped_seed);
+                next.append(&mut mapped);
+            }
+            mapped_seeds = next;
+        }
+
+        if let Some(mapped_min) = mapped_seeds.iter().map(|r| r.start).min() {
+            min = min.min(mapped_min);
+        }
+    }
+
+    min
+}
+
+/// Parses the numbers of the seeds line, pairs of the start and length of a range of seeds
+fn parse_seeds(input: &str, line: &str, values: &str) -> Result<Vec<u64>, ParseError> {
+    let seeds = values
+        .split_whitespace()
+        .map(|v| parse::parse::<u64>(input, v))
+        .collect::<Result<Vec<_>, _>>()?;
+    if seeds.is_empty() {
+        return Err(ParseError::after(input, line, "expected seeds"));
+    }
+    if seeds.len() % 2 != 0 {
+        let message = "expected pairs of the start and length of seed ranges";
+        return Err(ParseError::at(input, values.trim(), message));
+    }
+    Ok(seeds)
+}
+
+impl Solution for Almanac {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self> {
+        let mut seeds = None;
+        let mut maps = Vec::new();
+        for line in input.lines() {
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(values) = line.strip_prefix("seeds:") {
+                seeds = Some(parse_seeds(input, line, values)?);
+            } else if line.ends_with(':') {
+                maps.push(Map {
+                    ..Default::default()
+                });
+            } else {
+                let current_map = maps
+                    .last_mut()
+                    .ok_or_else(|| ParseError::at(input, line, "expected a map header"))?;
+                current_map.entries.push(MappingEntry::new(input, line)?);
+            }
+        }
+
+        let first_line = input.lines().next().unwrap_or(input);
+        let seeds = seeds.ok_or_else(|| {
+            ParseError::at(input, first_line, "expected `seeds: <start> <length> ...`")
+        })?;
+        Ok(Self { seeds, maps })
+    }
+
+    fn part1(&self) -> Result<u64> {
+        Ok(task1(self))
+    }
+
+    fn part2(&self) -> Result<u64> {
+        Ok(task2(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<()> {
+        let almanac = Almanac::parse(include_str!("../input_small.txt"))?;
+        assert_eq!(almanac.part1()?, 35);
+        assert_eq!(almanac.part2()?, 46);
+        Ok(())
+    }
+
+    #[test]
+    fn missing_seeds() {
+        for input in ["seeds:\n", "seed-to-soil map:\n50 98 2\n"] {
+            let Err(error) = Almanac::parse(input) else {
+                panic!("there are no seeds");
+            };
+            let error = error.downcast::<ParseError>().unwrap();
+            assert_eq!(error.line, 1);
+        }
+    }
+
+    #[test]
+    fn odd_seeds() {
+        let Err(error) = Almanac::parse("seeds: 79 14 55\n") else {
+            panic!("the last seed range has no length");
+        };
+        let error = error.downcast::<ParseError>().unwrap();
+        assert_eq!((error.line, error.column), (1, 8));
+        assert_eq!(error.text, "79 14 55");
+    }
+}
@@ -0,0 +1,94 @@
+use anyhow::Result;
+use common::{
+    parse::{self, ParseError},
+    Solution,
+};
+
+/// Times and record distances of the boat races
+pub struct Races {
+    times: Vec<u64>,
+    distances: Vec<u64>,
+    /// The single race of part 2
+    time: u64,
+    distance: u64,
+}
+
+/// The numbers of the line and the number they form when joined
+fn split_line(input: &str, line: Option<&str>) -> Result<(Vec<u64>, u64), ParseError> {
+    let line = line.ok_or_else(|| ParseError::after(input, input, "expected another line"))?;
+    let numbers = parse::split_once(input, line, ":")?.1;
+    let separate = numbers
+        .split_whitespace()
+        .map(|v| parse::parse::<u64>(input, v))
+        .collect::<Result<_, _>>()?;
+    Ok((separate, join_numbers(input, numbers.trim())?))
+}
+
+fn join_numbers(input: &str, numbers: &str) -> Result<u64, ParseError> {
+    // the kerning is bad, all numbers of a line belong to a single race
+    let number = numbers.split_whitespace().collect::<String>();
+    number
+        .parse::<u64>()
+        .map_err(|_| ParseError::at(input, numbers, "expected the joined numbers to fit a u64"))
+}
+
+fn calculate(times: &[u64], distances: &[u64]) -> usize {
+    times
+        .iter()
+        .zip(distances.iter())
+        .map(|(max, distance)| {
+            (1..*max)
+                .map(move |t| (max - t) * t)
+                .filter(|d| d > distance)
+                .count()
+        })
+        .product::<usize>()
+}
+
+impl Solution for Races {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        let mut lines = input.lines();
+        let (times, time) = split_line(input, lines.next())?;
+        let (distances, distance) = split_line(input, lines.next())?;
+        Ok(Self {
+            times,
+            distances,
+            time,
+            distance,
+        })
+    }
+
+    fn part1(&self) -> Result<usize> {
+        Ok(calculate(&self.times, &self.distances))
+    }
+
+    fn part2(&self) -> Result<usize> {
+        Ok(calculate(&[self.time], &[self.distance]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<()> {
+        let races = Races::parse(include_str!("../input_small.txt"))?;
+        assert_eq!(races.part1()?, 71503);
+        assert_eq!(races.part2()?, 71503);
+        Ok(())
+    }
+
+    #[test]
+    fn joined_number_too_large() {
+        let Err(error) = Races::parse("Time: 12345678901 2345678901\nDistance: 9 40\n") else {
+            panic!("the joined time does not fit a u64");
+        };
+        let error = error.downcast::<ParseError>().unwrap();
+        assert_eq!((error.line, error.column), (1, 7));
+        assert_eq!(error.text, "12345678901 2345678901");
+    }
+}
@@ -1,10 +1,9 @@
-use std::{
-    cmp::Ordering,
-    fs::File,
-    io::{BufRead, BufReader},
+use anyhow::Result;
+use common::{
+    parse::{self, ParseError},
+    Solution,
 };
-
-const DEVELOP: bool = false;
+use std::cmp::Ordering;
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 enum Ranking {
@@ -25,18 +24,13 @@ struct Hand {
 }
 
 impl Hand {
-    fn new(line: &str, use_joker: bool) -> Self {
-        let (cards, bid) = line.split_once(' ').unwrap();
-        let cards = cards
-            .chars()
-            .map(|c| Hand::get_card_value(c, use_joker))
-            .collect::<Vec<_>>();
-        let cards = [cards[0], cards[1], cards[2], cards[3], cards[4]];
+    fn new(cards: [char; 5], bid: usize, use_joker: bool) -> Self {
+        let cards = cards.map(|c| Hand::get_card_value(c, use_joker));
 
         Hand {
             rank: Hand::get_ranking(cards),
             cards,
-            bid: bid.parse::<usize>().unwrap(),
+            bid,
         }
     }
 
@@ -49,7 +43,7 @@ impl Hand {
             'J' => j,
             'T' => 10,
             '2'..='9' => c.to_digit(10).unwrap() as u8,
-            _ => panic!("Invalid value"),
+            _ => unreachable!("cards are checked while parsing"),
         }
     }
 
@@ -72,19 +66,19 @@ impl Hand {
         duplicates[max_index] += jokers;
 
         // check the rankings from high to low
-        if duplicates.iter().any(|v| *v == 5) {
+        if duplicates.contains(&5) {
             return Ranking::FiveOfAKind;
         }
-        if duplicates.iter().any(|v| *v == 4) {
+        if duplicates.contains(&4) {
             return Ranking::FourOfAKind;
         }
-        if duplicates.iter().any(|v| *v == 3) {
-            if duplicates.iter().any(|v| *v == 2) {
+        if duplicates.contains(&3) {
+            if duplicates.contains(&2) {
                 return Ranking::FullHouse;
             }
             return Ranking::ThreeOfAKind;
         }
-        if duplicates.iter().any(|v| *v == 2) {
+        if duplicates.contains(&2) {
             let two_pair = duplicates.iter().filter(|v| **v == 2).count() == 2;
             if two_pair {
                 return Ranking::TwoPair;
@@ -113,14 +107,16 @@ impl PartialEq for Hand {
     }
 }
 
-fn task(filename: &str, use_joker: bool) -> usize {
-    let file = File::open(filename).unwrap();
-    let reader = BufReader::new(file);
+const CARDS: &str = "AKQJT98765432";
+
+/// Hands of Camel Cards with their bids
+pub struct Hands(Vec<([char; 5], usize)>);
 
-    let mut hands = reader
-        .lines()
-        .flatten()
-        .map(|line| Hand::new(line.as_str(), use_joker))
+fn task(hands: &Hands, use_joker: bool) -> usize {
+    let mut hands = hands
+        .0
+        .iter()
+        .map(|(cards, bid)| Hand::new(*cards, *bid, use_joker))
         .collect::<Vec<_>>();
     hands.sort();
 
@@ -131,13 +127,44 @@ fn task(filename: &str, use_joker: bool) -> usize {
         .sum()
 }
 
-fn main() {
-    let filename = if DEVELOP {
-        "input_small.txt"
-    } else {
-        "input.txt"
-    };
+impl Solution for Hands {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        let mut hands = Vec::new();
+        for line in input.lines() {
+            let (text, bid) = parse::split_once(input, line, " ")?;
+            let cards = text.chars().collect::<Vec<_>>();
+            if let Some((index, card)) = text.char_indices().find(|(_, c)| !CARDS.contains(*c)) {
+                let card = &text[index..index + card.len_utf8()];
+                return Err(ParseError::at(input, card, "invalid card").into());
+            }
+            let cards = <[char; 5]>::try_from(cards)
+                .map_err(|_| ParseError::at(input, text, "expected five cards"))?;
+            hands.push((cards, parse::parse::<usize>(input, bid)?));
+        }
+        Ok(Self(hands))
+    }
 
-    println!("Task 1: {}", task(filename, false));
-    println!("Task 2: {}", task(filename, true));
+    fn part1(&self) -> Result<usize> {
+        Ok(task(self, false))
+    }
+
+    fn part2(&self) -> Result<usize> {
+        Ok(task(self, true))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<()> {
+        let hands = Hands::parse(include_str!("../input_small.txt"))?;
+        assert_eq!(hands.part1()?, 6440);
+        assert_eq!(hands.part2()?, 5905);
+        Ok(())
+    }
 }
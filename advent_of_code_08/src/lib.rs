@@ -0,0 +1,225 @@
+use anyhow::{bail, Result};
+use common::{parse::ParseError, Solution};
+use nom::{
+    bytes::complete::tag,
+    character::complete::alphanumeric1,
+    character::complete::char,
+    sequence::{delimited, separated_pair, terminated},
+    IResult,
+};
+use std::collections::{HashMap, HashSet};
+
+enum Direction {
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn new(input: &str, text: &str) -> Result<Self, ParseError> {
+        match text {
+            "R" => Ok(Direction::Right),
+            "L" => Ok(Direction::Left),
+            _ => Err(ParseError::at(input, text, "invalid direction")),
+        }
+    }
+
+    fn parse_directions(input: &str, line: &str) -> Result<Vec<Direction>, ParseError> {
+        line.char_indices()
+            .map(|(i, c)| Direction::new(input, &line[i..i + c.len_utf8()]))
+            .collect()
+    }
+}
+
+struct Network(HashMap<String, (String, String)>);
+
+impl Network {
+    fn parse_network<'a>(
+        input: &str,
+        lines: impl Iterator<Item = &'a str>,
+    ) -> Result<Self, ParseError> {
+        let nodes = lines
+            .map(|line| Network::parse_line(input, line))
+            .collect::<Result<Vec<_>, _>>()?;
+        let network = nodes
+            .iter()
+            .map(|&(source, _)| source)
+            .collect::<HashSet<_>>();
+        for &(_, (left, right)) in &nodes {
+            if let Some(node) = [left, right].into_iter().find(|n| !network.contains(n)) {
+                return Err(ParseError::at(input, node, "undefined node"));
+            }
+        }
+
+        let network = nodes
+            .into_iter()
+            .map(|(source, (left, right))| (source.to_owned(), (left.to_owned(), right.to_owned())))
+            .collect();
+        Ok(Self(network))
+    }
+
+    fn parse_line<'a>(
+        input: &str,
+        line: &'a str,
+    ) -> Result<(&'a str, (&'a str, &'a str)), ParseError> {
+        let (destination, source) = Network::split_source_from_destination(line)
+            .map_err(|error| nom_error(input, error, "expected `<node> = `"))?;
+        let (_, route) = Network::parse_route(destination)
+            .map_err(|error| nom_error(input, error, "expected `(<left>, <right>)`"))?;
+        Ok((source, route))
+    }
+
+    fn split_source_from_destination(input: &str) -> IResult<&str, &str> {
+        terminated(alphanumeric1, tag(" = "))(input)
+    }
+
+    fn parse_route(input: &str) -> IResult<&str, (&str, &str)> {
+        delimited(
+            char('('),
+            separated_pair(alphanumeric1, tag(", "), alphanumeric1),
+            char(')'),
+        )(input)
+    }
+
+    fn get(&self, node: &str) -> Option<&(String, String)> {
+        self.0.get(node)
+    }
+}
+
+/// Locates the error of a nom parser within the input
+fn nom_error(input: &str, error: nom::Err<nom::error::Error<&str>>, message: &str) -> ParseError {
+    match error {
+        nom::Err::Error(error) | nom::Err::Failure(error) => {
+            ParseError::at(input, error.input, message)
+        }
+        nom::Err::Incomplete(_) => ParseError::after(input, input, message),
+    }
+}
+
+fn calculate_steps(
+    start: String,
+    is_end: impl Fn(&String) -> bool,
+    directions: &[Direction],
+    network: &Network,
+) -> u64 {
+    let mut current = start;
+    for i in 1.. {
+        current = directions.iter().fold(current.clone(), |node, direction| {
+            // parsing made sure all routes lead to defined nodes
+            let node = network.get(&node).unwrap();
+            match direction {
+                Direction::Left => node.0.clone(),
+                Direction::Right => node.1.clone(),
+            }
+        });
+
+        if is_end(&current) {
+            return i * directions.len() as u64;
+        }
+    }
+    unreachable!()
+}
+
+fn task1(directions: &[Direction], network: &Network) -> Result<u64> {
+    if network.get("AAA").is_none() {
+        bail!("the network has no node AAA");
+    }
+    Ok(calculate_steps(
+        String::from("AAA"),
+        |n| n == "ZZZ",
+        directions,
+        network,
+    ))
+}
+
+fn task2(directions: &[Direction], network: &Network) -> u64 {
+    let steps = network
+        .0
+        .keys()
+        .filter(|n| n.ends_with('A'))
+        .cloned()
+        .map(|node| calculate_steps(node, |n| n.ends_with('Z'), directions, network))
+        .collect::<Vec<_>>();
+
+    lcm(&steps)
+}
+
+fn lcm(values: &[u64]) -> u64 {
+    values
+        .iter()
+        .fold(1, |acc, &value| acc / gcd(acc, value) * value)
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Left/right instructions and the network of nodes of the desert map
+pub struct Map {
+    directions: Vec<Direction>,
+    network: Network,
+}
+
+impl Solution for Map {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self> {
+        let mut lines = input.lines();
+        let line = lines
+            .next()
+            .ok_or_else(|| ParseError::after(input, input, "expected the directions"))?;
+        let directions = Direction::parse_directions(input, line)?;
+        if directions.is_empty() {
+            return Err(ParseError::at(input, line, "expected at least one direction").into());
+        }
+        lines.next(); // skip empty line
+        let network = Network::parse_network(input, lines)?;
+
+        Ok(Self {
+            directions,
+            network,
+        })
+    }
+
+    fn part1(&self) -> Result<u64> {
+        task1(&self.directions, &self.network)
+    }
+
+    fn part2(&self) -> Result<u64> {
+        Ok(task2(&self.directions, &self.network))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<()> {
+        let map = Map::parse(include_str!("../input_small.txt"))?;
+        assert_eq!(map.part2()?, 6);
+        Ok(())
+    }
+
+    #[test]
+    fn undefined_node() {
+        let Err(error) = Map::parse("LR\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)\n") else {
+            panic!("BBB is not defined");
+        };
+        let error = error.downcast::<ParseError>().unwrap();
+        assert_eq!((error.line, error.column), (3, 8));
+        assert_eq!(error.text, "BBB");
+    }
+
+    #[test]
+    fn no_directions() {
+        let Err(error) = Map::parse("\n\nAAA = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n") else {
+            panic!("there are no directions");
+        };
+        let error = error.downcast::<ParseError>().unwrap();
+        assert_eq!((error.line, error.column), (1, 1));
+    }
+}
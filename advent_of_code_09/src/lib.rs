@@ -0,0 +1,110 @@
+use anyhow::Result;
+use common::{
+    parse::{self, ParseError},
+    Solution,
+};
+
+/// Histories of the values reported by the oasis sensor
+pub struct Report(Vec<Vec<i32>>);
+
+#[derive(Debug, Clone, Copy)]
+enum Direction {
+    First,
+    Last,
+}
+
+fn calculate(report: &Report, direction: Direction) -> i32 {
+    let mut sum = 0;
+    for history in report.0.iter() {
+        let mut numbers = vec![history.clone()];
+
+        for i in 0.. {
+            let mut next = Vec::new();
+            for j in 1..numbers[i].len() {
+                next.push(numbers[i][j] - numbers[i][j - 1]);
+            }
+            let all_zeros = next.iter().all(|v| *v == 0);
+            numbers.push(next);
+
+            if all_zeros {
+                break;
+            }
+        }
+
+        let last_row = numbers.last_mut().unwrap();
+        match direction {
+            Direction::Last => last_row.push(0),
+            Direction::First => last_row.insert(0, 0),
+        }
+
+        for i in (1..numbers.len()).rev() {
+            match direction {
+                Direction::Last => {
+                    let next = numbers[i].last().unwrap() + numbers[i - 1].last().unwrap();
+                    numbers[i - 1].push(next);
+                }
+                Direction::First => {
+                    let next = numbers[i - 1].first().unwrap() - numbers[i].first().unwrap();
+                    numbers[i - 1].insert(0, next);
+                }
+            }
+        }
+
+        sum += match direction {
+            Direction::Last => numbers[0].last().unwrap(),
+            Direction::First => numbers[0].first().unwrap(),
+        }
+    }
+
+    sum
+}
+
+impl Solution for Report {
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self> {
+        let mut histories = Vec::new();
+        for line in input.lines() {
+            let history = line
+                .split_whitespace()
+                .map(|v| parse::parse::<i32>(input, v))
+                .collect::<Result<Vec<_>, _>>()?;
+            if history.is_empty() {
+                return Err(ParseError::at(input, line, "expected a history of values").into());
+            }
+            histories.push(history);
+        }
+        Ok(Self(histories))
+    }
+
+    fn part1(&self) -> Result<i32> {
+        Ok(calculate(self, Direction::Last))
+    }
+
+    fn part2(&self) -> Result<i32> {
+        Ok(calculate(self, Direction::First))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<()> {
+        let report = Report::parse(include_str!("../input_small.txt"))?;
+        assert_eq!(report.part1()?, 114);
+        assert_eq!(report.part2()?, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn empty_history() {
+        let Err(error) = Report::parse("1 2\n\n3 4\n") else {
+            panic!("the second history is empty");
+        };
+        let error = error.downcast::<ParseError>().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+}
@@ -0,0 +1,278 @@
+use anyhow::Result;
+use common::{
+    geometry::{Direction, Position},
+    grid::Grid,
+    parse::ParseError,
+    Solution,
+};
+use core::fmt;
+
+struct Literals;
+
+impl Literals {
+    const NORTH_TO_SOUTH: u8 = 124; // |
+    const WEST_TO_EAST: u8 = 45; // -
+    const NORTH_TO_EAST: u8 = 76; // L
+    const NORTH_TO_WEST: u8 = 74; // J
+    const WEST_TO_SOUTH: u8 = 55; // 7
+    const EAST_TO_SOUTH: u8 = 70; // F
+    const DOT: u8 = 46; // .
+    const START: u8 = 83; // S
+}
+
+/// Field of pipes the animal ran into
+pub struct Maze(Grid<u8>);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Open,
+    Closed,
+    Path,
+    Loop,
+}
+
+struct FloodMap(Grid<Tile>);
+
+impl FloodMap {
+    fn new(width: usize, heigth: usize) -> Self {
+        // every second row and column is a path between the tiles:
+        // Closed | Path | Closed | Path | ... | Closed
+        // Path   | Path | Path   | Path | ... | Path
+        let mut map = Grid::filled(width * 2 - 1, heigth * 2 - 1, Tile::Path);
+        for y in (0..map.height()).step_by(2) {
+            for x in (0..map.width()).step_by(2) {
+                map[Position::new(x, y)] = Tile::Closed;
+            }
+        }
+        Self(map)
+    }
+
+    fn add_loop(&mut self, from: Position, to: Position) {
+        let from = Position::new(from.x * 2, from.y * 2);
+        let to = Position::new(to.x * 2, to.y * 2);
+        let over = Position::new((from.x + to.x) / 2, (from.y + to.y) / 2);
+
+        self.0[from] = Tile::Loop;
+        self.0[over] = Tile::Loop;
+        self.0[to] = Tile::Loop;
+    }
+
+    fn flood_fill_map(&mut self) {
+        let (width, heigth) = (self.0.width(), self.0.height());
+        for x in 0..width {
+            self.flood_fill(Position::new(x, 0));
+            self.flood_fill(Position::new(x, heigth - 1));
+        }
+        for y in 0..heigth {
+            self.flood_fill(Position::new(0, y));
+            self.flood_fill(Position::new(width - 1, y));
+        }
+    }
+
+    fn flood_fill(&mut self, start: Position) {
+        if self.0[start] != Tile::Closed && self.0[start] != Tile::Path {
+            return;
+        }
+
+        self.0[start] = Tile::Open;
+
+        let neighbors = self.0.neighbours(start).collect::<Vec<_>>();
+        for neighbor in neighbors {
+            self.flood_fill(neighbor);
+        }
+    }
+
+    fn get_closed_tiles(&self) -> usize {
+        self.0
+            .iter()
+            .filter(|(_, &tile)| tile == Tile::Closed)
+            .count()
+    }
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Tile::Closed => 'I',
+            Tile::Open => 'O',
+            Tile::Loop => '=',
+            Tile::Path => ' ',
+        };
+        write!(f, "{c}")
+    }
+}
+
+impl Maze {
+    fn get_length(&self) -> usize {
+        let start = self.find_starting_position();
+
+        // find all fields next to start
+        let neighbors = self.0.neighbours(start).collect::<Vec<_>>();
+
+        // calculate the route from all fields next to start to start again
+        neighbors
+            .iter()
+            .filter_map(|&node| self.get_path_length(start, node, start))
+            .max()
+            .unwrap()
+    }
+
+    fn get_enclosed_tiles(&self) -> usize {
+        // create open/ enclosed map
+        let mut map = FloodMap::new(self.0.width(), self.0.height());
+        //println!("Created: \n{}", map.0);
+
+        // creat path and mark tiles
+        let start = self.find_starting_position();
+        let next = Position::new(start.x, start.y + 1);
+        self.mark_path(&mut map, start, next, start);
+        //println!("Mark Path:\n{}", map.0);
+
+        // flood fill from edge of grid
+        map.flood_fill_map();
+        //println!("Flood fill:\n{}", map.0);
+
+        // search all enclosed tiles
+        map.get_closed_tiles()
+    }
+
+    fn find_starting_position(&self) -> Position {
+        self.0
+            .iter()
+            .find(|(_, &pipe)| pipe == Literals::START)
+            .map(|(position, _)| position)
+            .unwrap()
+    }
+
+    /// Directions a pipe connects
+    fn get_connections(pipe: u8) -> Option<[Direction; 2]> {
+        match pipe {
+            Literals::DOT | Literals::START => None,
+            Literals::NORTH_TO_SOUTH => Some([Direction::Up, Direction::Down]),
+            Literals::WEST_TO_EAST => Some([Direction::Left, Direction::Right]),
+            Literals::NORTH_TO_EAST => Some([Direction::Up, Direction::Right]),
+            Literals::NORTH_TO_WEST => Some([Direction::Up, Direction::Left]),
+            Literals::WEST_TO_SOUTH => Some([Direction::Left, Direction::Down]),
+            Literals::EAST_TO_SOUTH => Some([Direction::Right, Direction::Down]),
+            _ => unreachable!("pipes are checked while parsing"),
+        }
+    }
+
+    fn get_next_field(&self, previous: Position, current: Position) -> Option<Position> {
+        let connections = Self::get_connections(self.0[current])?;
+        let entry = Direction::ALL
+            .into_iter()
+            .find(|&direction| current.step(direction) == Some(previous))?;
+
+        let exit = match connections {
+            [from, to] if from == entry => to,
+            [to, from] if from == entry => to,
+            _ => return None,
+        };
+        self.0.step(current, exit)
+    }
+
+    fn mark_path(
+        &self,
+        map: &mut FloodMap,
+        mut previous: Position,
+        mut current: Position,
+        end: Position,
+    ) {
+        map.add_loop(previous, current);
+        while current != end {
+            match self.get_next_field(previous, current) {
+                Some(next) => {
+                    previous = current;
+                    current = next;
+                    map.add_loop(previous, current);
+                }
+                None => panic!("Invalid path"),
+            }
+        }
+    }
+
+    fn get_path_length(
+        &self,
+        mut previous: Position,
+        mut current: Position,
+        end: Position,
+    ) -> Option<usize> {
+        for i in 0.. {
+            if current == end {
+                return Some(i);
+            }
+            match self.get_next_field(previous, current) {
+                Some(next) => {
+                    previous = current;
+                    current = next;
+                }
+                None => {
+                    return None;
+                }
+            }
+        }
+        unreachable!()
+    }
+}
+
+impl Solution for Maze {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        let grid = Grid::parse_with(input, |c| match c {
+            '|' | '-' | 'L' | 'J' | '7' | 'F' | '.' | 'S' => Ok(c as u8),
+            _ => Err("invalid pipe"),
+        })?;
+        if !grid.iter().any(|(_, &pipe)| pipe == Literals::START) {
+            return Err(ParseError::after(input, input, "expected a starting position `S`").into());
+        }
+        Ok(Maze(grid))
+    }
+
+    fn part1(&self) -> Result<usize> {
+        Ok(self.get_length().div_ceil(2))
+    }
+
+    fn part2(&self) -> Result<usize> {
+        Ok(self.get_enclosed_tiles())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_1() -> Result<()> {
+        let maze = Maze::parse(include_str!("../input_small.txt"))?;
+        assert_eq!(maze.part1()?, 8);
+        assert_eq!(maze.part2()?, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        let maze = Maze::parse(include_str!("../input_small2.txt"))?;
+        assert_eq!(maze.part1()?, 23);
+        assert_eq!(maze.part2()?, 4);
+        Ok(())
+    }
+
+    #[test]
+    fn example_3() -> Result<()> {
+        let maze = Maze::parse(include_str!("../input_small3.txt"))?;
+        assert_eq!(maze.part1()?, 70);
+        assert_eq!(maze.part2()?, 8);
+        Ok(())
+    }
+
+    #[test]
+    fn example_4() -> Result<()> {
+        let maze = Maze::parse(include_str!("../input_small4.txt"))?;
+        assert_eq!(maze.part1()?, 80);
+        assert_eq!(maze.part2()?, 10);
+        Ok(())
+    }
+}
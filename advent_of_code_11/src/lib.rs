@@ -0,0 +1,86 @@
+use anyhow::Result;
+use common::{geometry::Position, Solution};
+
+/// Image of the galaxies observed by the elf
+pub struct Image {
+    galaxies: Vec<Position>,
+    width: usize,
+    heigth: usize,
+}
+
+fn calculate(image: &Image, multiply: usize) -> usize {
+    let (width, heigth) = (image.width, image.heigth);
+    let mut galaxies = image.galaxies.clone();
+
+    // found rows and columns without any galaxies
+    let rows = (0..=heigth)
+        .filter(|&y| galaxies.iter().all(|g| g.y != y))
+        .collect::<Vec<_>>();
+    let columns = (0..=width)
+        .filter(|&x| galaxies.iter().all(|g| g.x != x))
+        .collect::<Vec<_>>();
+
+    // correct galaxy coordinates
+    for galaxy in &mut galaxies {
+        galaxy.y += (multiply - 1) * rows.iter().filter(|&&r| r < galaxy.y).count();
+        galaxy.x += (multiply - 1) * columns.iter().filter(|&&c| c < galaxy.x).count();
+    }
+
+    // calculate manhattan distance of each pair
+    let mut distance = 0;
+    for (index, galaxy_1) in galaxies.iter().enumerate() {
+        for galaxy_2 in galaxies.iter().skip(index) {
+            distance += galaxy_1.manhattan_distance(*galaxy_2);
+        }
+    }
+
+    distance
+}
+
+impl Solution for Image {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        // create vec with all galaxy coordinates
+        let mut galaxies = Vec::new();
+        let mut width = 0;
+        let mut heigth = 0;
+        for (y, line) in input.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                if c == '#' {
+                    galaxies.push(Position::new(x, y));
+                }
+                width = x;
+            }
+            heigth = y;
+        }
+
+        Ok(Self {
+            galaxies,
+            width,
+            heigth,
+        })
+    }
+
+    fn part1(&self) -> Result<usize> {
+        Ok(calculate(self, 2))
+    }
+
+    fn part2(&self) -> Result<usize> {
+        Ok(calculate(self, 1_000_000))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<()> {
+        let image = Image::parse(include_str!("../input_small.txt"))?;
+        assert_eq!(image.part1()?, 374);
+        assert_eq!(image.part2()?, 82000210);
+        Ok(())
+    }
+}
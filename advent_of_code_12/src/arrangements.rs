@@ -0,0 +1,183 @@
+use crate::{fit_groups, too_many_arrangements, Record, Records, State, StateIndex, Token};
+use anyhow::{anyhow, Result};
+use std::fmt;
+
+/// Concrete row of springs without unknown tokens
+#[derive(Clone, PartialEq, Eq)]
+pub struct Arrangement(Vec<Token>);
+
+impl fmt::Debug for Arrangement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for token in &self.0 {
+            write!(f, "{token:?}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The arrangements of a record in order, operational springs before damaged ones.
+///
+/// Each arrangement is built when it is needed, skipping arrangements does not build them.
+pub struct Arrangements {
+    tokens: Vec<Token>,
+    groups: Vec<u32>,
+    states: StateIndex,
+    /// Number of arrangements of the tokens from an index on, for each state before the index
+    completions: Vec<u64>,
+    next: u64,
+    end: u64,
+}
+
+impl Arrangements {
+    /// `None` if there are too many arrangements to count in a `u64`
+    fn new(tokens: Vec<Token>, groups: Vec<u32>) -> Option<Self> {
+        let groups = fit_groups(&groups, tokens.len());
+        let states = StateIndex::new(&groups);
+        let mut completions = vec![0; (tokens.len() + 1) * states.len()];
+        let last = tokens.len() * states.len();
+        for state in states.states() {
+            if state.is_final(&groups) {
+                completions[last + states.index(state)] = 1;
+            }
+        }
+        for (i, &token) in tokens.iter().enumerate().rev() {
+            for state in states.states() {
+                completions[i * states.len() + states.index(state)] = state
+                    .next(token, &groups)
+                    .try_fold(0u64, |sum, (_, next)| {
+                        sum.checked_add(completions[(i + 1) * states.len() + states.index(next)])
+                    })?;
+            }
+        }
+
+        let end = completions[states.index(State::START)];
+        Some(Self {
+            tokens,
+            groups,
+            states,
+            completions,
+            next: 0,
+            end,
+        })
+    }
+
+    /// Skips the first arrangements
+    pub fn offset(mut self, offset: u64) -> Self {
+        self.next = self.next.saturating_add(offset).min(self.end);
+        self
+    }
+
+    /// Stops after the given number of arrangements
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.end = self.end.min(self.next.saturating_add(limit));
+        self
+    }
+
+    /// The arrangement at the index, following the counts of the completions
+    fn get(&self, mut index: u64) -> Arrangement {
+        let mut state = State::START;
+        let mut arrangement = Vec::with_capacity(self.tokens.len());
+        for (i, &token) in self.tokens.iter().enumerate() {
+            for (concrete, next) in state.next(token, &self.groups) {
+                let count = self.completions[(i + 1) * self.states.len() + self.states.index(next)];
+                if index < count {
+                    arrangement.push(concrete);
+                    state = next;
+                    break;
+                }
+                index -= count;
+            }
+        }
+        Arrangement(arrangement)
+    }
+}
+
+impl Iterator for Arrangements {
+    type Item = Arrangement;
+
+    fn next(&mut self) -> Option<Arrangement> {
+        if self.next >= self.end {
+            return None;
+        }
+        let arrangement = self.get(self.next);
+        self.next += 1;
+        Some(arrangement)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Arrangement> {
+        self.next = self.next.saturating_add(n as u64).min(self.end);
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = usize::try_from(self.end - self.next).ok();
+        (remaining.unwrap_or(usize::MAX), remaining)
+    }
+}
+
+impl Record {
+    fn arrangements(&self) -> Option<Arrangements> {
+        Arrangements::new(self.tokens.clone(), self.groups.clone())
+    }
+}
+
+impl Records {
+    /// Arrangements of the record on the line, starting at 0
+    pub fn arrangements(&self, line: usize) -> Result<Arrangements> {
+        let record = self
+            .records
+            .get(line)
+            .ok_or_else(|| anyhow!("there is no record on line {}", line + 1))?;
+        record.arrangements().ok_or_else(too_many_arrangements)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::count_arrangements;
+    use common::Solution;
+
+    #[test]
+    fn enumerate() {
+        let records = Records::parse(include_str!("../input_small.txt")).unwrap();
+        let arrangements = records
+            .arrangements(1)
+            .unwrap()
+            .map(|arrangement| format!("{arrangement:?}"))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            arrangements,
+            [
+                "..#...#...###.",
+                "..#..#....###.",
+                ".#....#...###.",
+                ".#...#....###."
+            ]
+        );
+
+        for record in &records.records {
+            assert_eq!(
+                Some(record.arrangements().unwrap().count() as u64),
+                count_arrangements(&record.tokens, &record.groups)
+            );
+        }
+    }
+
+    #[test]
+    fn offset_and_limit() {
+        let record = Record::new("?###???????? 3,2,1", "?###???????? 3,2,1").unwrap();
+        let arrangements = || record.arrangements().unwrap();
+        let all = arrangements().collect::<Vec<_>>();
+        assert_eq!(all.len(), 10);
+        assert_eq!(
+            arrangements().offset(3).limit(4).collect::<Vec<_>>(),
+            all[3..7]
+        );
+        assert_eq!(arrangements().nth(9), Some(all[9].clone()));
+        assert_eq!(arrangements().offset(20).next(), None);
+
+        let record = Record::new("?? 4000000000", "?? 4000000000").unwrap();
+        assert_eq!(record.arrangements().unwrap().count(), 0);
+    }
+}
@@ -0,0 +1,352 @@
+mod arrangements;
+mod nonogram;
+
+pub use arrangements::{Arrangement, Arrangements};
+pub use nonogram::{Nonogram, Picture, Solutions};
+
+use anyhow::{anyhow, Result};
+use common::{
+    parse::{self, ParseError},
+    Solution,
+};
+use core::fmt;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Token {
+    Unknown,
+    Operational,
+    Damaged,
+}
+
+impl Token {
+    fn new(input: &str, token: &str) -> Result<Self, ParseError> {
+        let mut chars = token.chars();
+        match (chars.next().and_then(Token::from_char), chars.next()) {
+            (Some(token), None) => Ok(token),
+            _ => Err(ParseError::at(input, token, "invalid spring")),
+        }
+    }
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '?' => Some(Token::Unknown),
+            '.' => Some(Token::Operational),
+            '#' => Some(Token::Damaged),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Debug for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let token = match *self {
+            Token::Unknown => '?',
+            Token::Operational => '.',
+            Token::Damaged => '#',
+        };
+        write!(f, "{token}")
+    }
+}
+
+/// Number of copies of each record after unfolding it
+const UNFOLD_FACTOR: usize = 5;
+
+/// Condition records of the springs
+pub struct Records {
+    records: Vec<Record>,
+    unfold: Unfold,
+    /// Called after each unfolded record with the number of records done and of all records
+    progress: Option<Box<dyn Fn(usize, usize)>>,
+}
+
+impl Records {
+    pub fn with_unfold(mut self, unfold: Unfold) -> Self {
+        self.unfold = unfold;
+        self
+    }
+
+    /// Reports the progress of part 2 after each record
+    pub fn with_progress(mut self, progress: impl Fn(usize, usize) + 'static) -> Self {
+        self.progress = Some(Box::new(progress));
+        self
+    }
+}
+
+/// How the records are unfolded for part 2
+#[derive(Clone, Copy)]
+pub struct Unfold {
+    factor: usize,
+    /// Spring between the copies of a record
+    joiner: Token,
+}
+
+impl Unfold {
+    /// Unfolds each record into `factor` copies, joined by the spring `joiner`
+    pub fn new(factor: usize, joiner: char) -> Result<Self> {
+        let joiner =
+            Token::from_char(joiner).ok_or_else(|| anyhow!("invalid spring `{joiner}`"))?;
+        Ok(Self { factor, joiner })
+    }
+}
+
+impl Default for Unfold {
+    fn default() -> Self {
+        Self {
+            factor: UNFOLD_FACTOR,
+            joiner: Token::Unknown,
+        }
+    }
+}
+
+struct Record {
+    tokens: Vec<Token>,
+    groups: Vec<u32>,
+}
+
+impl Record {
+    fn new(input: &str, line: &str) -> Result<Self, ParseError> {
+        let (tokens, groups) = parse::split_once(input, line, " ")?;
+        let tokens = tokens
+            .char_indices()
+            .map(|(i, c)| Token::new(input, &tokens[i..i + c.len_utf8()]))
+            .collect::<Result<Vec<_>, _>>()?;
+        let groups = groups
+            .split(',')
+            .map(|g| parse::parse::<u32>(input, g))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { tokens, groups })
+    }
+
+    fn unfold(&self, unfold: Unfold) -> Record {
+        let mut tokens = Vec::new();
+        for copy in 0..unfold.factor {
+            if copy > 0 {
+                tokens.push(unfold.joiner);
+            }
+            tokens.extend(&self.tokens);
+        }
+        Self {
+            tokens,
+            groups: self.groups.repeat(unfold.factor),
+        }
+    }
+}
+
+impl Solution for Records {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self> {
+        let records = input
+            .lines()
+            .map(|line| Record::new(input, line))
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            records,
+            unfold: Unfold::default(),
+            progress: None,
+        })
+    }
+
+    fn part1(&self) -> Result<u64> {
+        self.records.iter().try_fold(0, |sum, record| {
+            add_arrangements(sum, calculate_arrangements(record)?)
+        })
+    }
+
+    fn part2(&self) -> Result<u64> {
+        let mut sum = 0;
+        for (i, record) in self.records.iter().enumerate() {
+            sum = add_arrangements(sum, calculate_arrangements(&record.unfold(self.unfold))?)?;
+            if let Some(progress) = &self.progress {
+                progress(i + 1, self.records.len());
+            }
+        }
+        Ok(sum)
+    }
+}
+
+fn calculate_arrangements(record: &Record) -> Result<u64> {
+    count_arrangements(&record.tokens, &record.groups).ok_or_else(too_many_arrangements)
+}
+
+fn add_arrangements(sum: u64, count: u64) -> Result<u64> {
+    sum.checked_add(count).ok_or_else(too_many_arrangements)
+}
+
+fn too_many_arrangements() -> anyhow::Error {
+    anyhow!("there are more than {} arrangements", u64::MAX)
+}
+
+/// Progress through the groups of damaged springs while going through the tokens
+#[derive(Clone, Copy)]
+struct State {
+    /// Index of the current group
+    group: usize,
+    /// Length of the run of damaged springs in the current group so far
+    run: usize,
+}
+
+impl State {
+    const START: State = State { group: 0, run: 0 };
+
+    /// The states after the token, each with the concrete token leading to it
+    fn next(self, token: Token, groups: &[u32]) -> impl Iterator<Item = (Token, State)> {
+        // an operational spring ends the run, which must complete the group
+        let operational = match token {
+            Token::Damaged => None,
+            _ if self.run == 0 => Some(self),
+            _ if groups.get(self.group) == Some(&(self.run as u32)) => Some(State {
+                group: self.group + 1,
+                run: 0,
+            }),
+            _ => None,
+        };
+        let damaged = match token {
+            Token::Operational => None,
+            _ if self.group < groups.len() && self.run < groups[self.group] as usize => {
+                Some(State {
+                    group: self.group,
+                    run: self.run + 1,
+                })
+            }
+            _ => None,
+        };
+
+        [
+            operational.map(|state| (Token::Operational, state)),
+            damaged.map(|state| (Token::Damaged, state)),
+        ]
+        .into_iter()
+        .flatten()
+    }
+
+    /// Whether all groups are completed, the last one possibly by the last token
+    fn is_final(self, groups: &[u32]) -> bool {
+        self.group == groups.len() && self.run == 0
+            || self.group + 1 == groups.len() && self.run == groups[self.group] as usize
+    }
+}
+
+/// Shortens the groups longer than the tokens to one more than the tokens.
+///
+/// Such groups have no arrangement either way, but the table of states stays as small as the
+/// tokens.
+fn fit_groups(groups: &[u32], length: usize) -> Vec<u32> {
+    let longest = u32::try_from(length + 1).unwrap_or(u32::MAX);
+    groups.iter().map(|&group| group.min(longest)).collect()
+}
+
+/// Index of each state in a table of the states for the groups
+struct StateIndex {
+    groups: usize,
+    longest: usize,
+}
+
+impl StateIndex {
+    fn new(groups: &[u32]) -> Self {
+        Self {
+            groups: groups.len(),
+            longest: groups.iter().max().copied().unwrap_or(0) as usize,
+        }
+    }
+
+    fn len(&self) -> usize {
+        (self.groups + 1) * (self.longest + 1)
+    }
+
+    fn states(&self) -> impl Iterator<Item = State> + '_ {
+        (0..=self.groups).flat_map(|group| (0..=self.longest).map(move |run| State { group, run }))
+    }
+
+    fn index(&self, state: State) -> usize {
+        state.group * (self.longest + 1) + state.run
+    }
+}
+
+/// Counts the arrangements of the unknown tokens that match the groups of damaged springs.
+///
+/// Goes through the tokens once, counting the arrangements of the tokens so far that end in
+/// each state. Returns `None` if there are too many arrangements to count in a `u64`.
+fn count_arrangements(tokens: &[Token], groups: &[u32]) -> Option<u64> {
+    let groups = &fit_groups(groups, tokens.len());
+    let states = StateIndex::new(groups);
+    let mut counts = vec![0u64; states.len()];
+    counts[states.index(State::START)] = 1;
+    for &token in tokens {
+        let mut next = vec![0u64; states.len()];
+        for state in states.states() {
+            let count = counts[states.index(state)];
+            if count == 0 {
+                continue;
+            }
+            for (_, next_state) in state.next(token, groups) {
+                let next = &mut next[states.index(next_state)];
+                *next = next.checked_add(count)?;
+            }
+        }
+        counts = next;
+    }
+
+    let total = states
+        .states()
+        .filter(|state| state.is_final(groups))
+        .try_fold(0u64, |sum, state| {
+            sum.checked_add(counts[states.index(state)])
+        });
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<()> {
+        let records = Records::parse(include_str!("../input_small.txt"))?;
+        assert_eq!(records.part1()?, 21);
+        Ok(())
+    }
+
+    #[test]
+    fn example_unfolded() -> Result<()> {
+        let records = Records::parse(include_str!("../input_small.txt"))?;
+        assert_eq!(records.part2()?, 525152);
+        Ok(())
+    }
+
+    #[test]
+    fn unfold() -> Result<()> {
+        let records = Records::parse(include_str!("../input_small.txt"))?;
+        // joined by an operational spring the copies are arranged independently
+        let records = records.with_unfold(Unfold::new(2, '.')?);
+        assert_eq!(records.part2()?, 1 + 4 * 4 + 1 + 1 + 4 * 4 + 10 * 10);
+        assert!(Unfold::new(2, 'x').is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn single_rows() {
+        let count = |line: &str| {
+            let record = Record::new(line, line).unwrap();
+            count_arrangements(&record.tokens, &record.groups)
+        };
+        assert_eq!(count("???.### 1,1,3"), Some(1));
+        assert_eq!(count("?###???????? 3,2,1"), Some(10));
+        assert_eq!(count("#.# 2"), Some(0));
+        assert_eq!(count("?? 3"), Some(0));
+        assert_eq!(count("..# 1"), Some(1));
+        assert_eq!(count("? 4000000000"), Some(0));
+        assert_eq!(count("??? 1,4000000000"), Some(0));
+    }
+
+    #[test]
+    fn overflow() -> Result<()> {
+        let records = Records::parse("?????????? 1\n")?.with_unfold(Unfold::new(40, '?')?);
+        assert!(records.part2().is_err());
+        let records = Records::parse("?????????? 1\n")?.with_unfold(Unfold::new(10, '?')?);
+        assert!(records.part2().is_ok());
+        Ok(())
+    }
+}
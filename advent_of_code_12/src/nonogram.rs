@@ -0,0 +1,235 @@
+use crate::{fit_groups, State, StateIndex, Token};
+use common::{
+    geometry::Position,
+    grid::Grid,
+    parse::{self, ParseError},
+};
+use std::fmt;
+
+/// Picture puzzle, the groups of damaged springs of each row and column are given
+pub struct Nonogram {
+    rows: Vec<Vec<u32>>,
+    columns: Vec<Vec<u32>>,
+}
+
+/// Solved nonogram, damaged springs are the filled cells
+#[derive(Clone, PartialEq, Eq)]
+pub struct Picture(Grid<Token>);
+
+impl fmt::Display for Picture {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.0.rows() {
+            for token in row {
+                write!(f, "{token:?}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+pub enum Solutions {
+    None,
+    Unique(Picture),
+    /// Two of the solutions
+    Multiple(Picture, Picture),
+}
+
+impl Nonogram {
+    pub fn new(rows: Vec<Vec<u32>>, columns: Vec<Vec<u32>>) -> Self {
+        Self { rows, columns }
+    }
+
+    /// Reads the groups of the rows and then the ones of the columns, separated by an empty line.
+    ///
+    /// Each line holds the groups of one row or column separated by `,`, `0` if there are none.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut lines = input.lines();
+        let rows = lines
+            .by_ref()
+            .take_while(|line| !line.is_empty())
+            .map(|line| parse_groups(input, line))
+            .collect::<Result<_, _>>()?;
+        let columns = lines
+            .map(|line| parse_groups(input, line))
+            .collect::<Result<_, _>>()?;
+        Ok(Self::new(rows, columns))
+    }
+
+    /// Solves the lines one at a time as far as possible and guesses a cell when they are stuck
+    pub fn solve(&self) -> Solutions {
+        let grid = Grid::filled(self.columns.len(), self.rows.len(), Token::Unknown);
+        let mut solutions = Vec::new();
+        self.search(grid, &mut solutions);
+
+        let mut solutions = solutions.into_iter().map(Picture);
+        match (solutions.next(), solutions.next()) {
+            (None, _) => Solutions::None,
+            (Some(solution), None) => Solutions::Unique(solution),
+            (Some(first), Some(second)) => Solutions::Multiple(first, second),
+        }
+    }
+
+    /// Collects up to two solutions of the partly solved grid
+    fn search(&self, mut grid: Grid<Token>, solutions: &mut Vec<Grid<Token>>) {
+        if !self.propagate(&mut grid) {
+            return;
+        }
+
+        let Some(position) = grid
+            .positions()
+            .find(|&position| grid[position] == Token::Unknown)
+        else {
+            solutions.push(grid);
+            return;
+        };
+        for token in [Token::Damaged, Token::Operational] {
+            if solutions.len() >= 2 {
+                return;
+            }
+            let mut guess = grid.clone();
+            guess[position] = token;
+            self.search(guess, solutions);
+        }
+    }
+
+    /// Solves the lines until none of them changes, `false` if a line has no arrangement
+    fn propagate(&self, grid: &mut Grid<Token>) -> bool {
+        let mut changed = true;
+        while changed {
+            changed = false;
+
+            for (y, groups) in self.rows.iter().enumerate() {
+                let Some(line) = solve_line(grid.row(y), groups) else {
+                    return false;
+                };
+                if line != grid.row(y) {
+                    grid.row_mut(y).copy_from_slice(&line);
+                    changed = true;
+                }
+            }
+
+            for (x, groups) in self.columns.iter().enumerate() {
+                let column = grid.column(x).copied().collect::<Vec<_>>();
+                let Some(line) = solve_line(&column, groups) else {
+                    return false;
+                };
+                if line != column {
+                    for (y, token) in line.into_iter().enumerate() {
+                        grid[Position::new(x, y)] = token;
+                    }
+                    changed = true;
+                }
+            }
+        }
+        true
+    }
+}
+
+fn parse_groups(input: &str, line: &str) -> Result<Vec<u32>, ParseError> {
+    let groups = line
+        .split(',')
+        .map(|group| parse::parse::<u32>(input, group.trim()))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(groups.into_iter().filter(|&group| group > 0).collect())
+}
+
+/// Fixes the unknown tokens that are the same in all arrangements of the line.
+///
+/// Returns `None` if the line has no arrangement.
+fn solve_line(tokens: &[Token], groups: &[u32]) -> Option<Vec<Token>> {
+    let groups = &fit_groups(groups, tokens.len());
+    let states = StateIndex::new(groups);
+    let at = |i: usize, state: State| i * states.len() + states.index(state);
+
+    // states reachable by the tokens before each index
+    let mut reachable = vec![false; (tokens.len() + 1) * states.len()];
+    reachable[at(0, State::START)] = true;
+    for (i, &token) in tokens.iter().enumerate() {
+        for state in states.states() {
+            if reachable[at(i, state)] {
+                for (_, next) in state.next(token, groups) {
+                    reachable[at(i + 1, next)] = true;
+                }
+            }
+        }
+    }
+
+    // states from which the tokens from each index on can complete the groups
+    let mut completable = vec![false; reachable.len()];
+    for state in states.states() {
+        completable[at(tokens.len(), state)] = state.is_final(groups);
+    }
+    for (i, &token) in tokens.iter().enumerate().rev() {
+        for state in states.states() {
+            completable[at(i, state)] = state
+                .next(token, groups)
+                .any(|(_, next)| completable[at(i + 1, next)]);
+        }
+    }
+    if !completable[at(0, State::START)] {
+        return None;
+    }
+
+    let line = tokens
+        .iter()
+        .enumerate()
+        .map(|(i, &token)| {
+            let (mut operational, mut damaged) = (false, false);
+            for state in states.states().filter(|&state| reachable[at(i, state)]) {
+                for (concrete, next) in state.next(token, groups) {
+                    if completable[at(i + 1, next)] {
+                        match concrete {
+                            Token::Operational => operational = true,
+                            _ => damaged = true,
+                        }
+                    }
+                }
+            }
+            match (operational, damaged) {
+                (true, false) => Token::Operational,
+                (false, true) => Token::Damaged,
+                _ => Token::Unknown,
+            }
+        })
+        .collect();
+    Some(line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line() {
+        let tokens = [Token::Unknown; 5];
+        assert_eq!(
+            format!("{:?}", solve_line(&tokens, &[4]).unwrap()),
+            "[?, #, #, #, ?]"
+        );
+        assert_eq!(solve_line(&tokens, &[3, 2]), None);
+        assert_eq!(solve_line(&tokens, &[4_000_000_000]), None);
+    }
+
+    #[test]
+    fn unique() {
+        let nonogram = Nonogram::parse("1,1\n5\n5\n3\n1\n\n2\n4\n4\n4\n2\n").unwrap();
+        let Solutions::Unique(picture) = nonogram.solve() else {
+            panic!("expected a unique solution");
+        };
+        assert_eq!(picture.to_string(), ".#.#.\n#####\n#####\n.###.\n..#..\n");
+    }
+
+    #[test]
+    fn multiple_and_none() {
+        let nonogram = Nonogram::parse("1\n1\n\n1\n1\n").unwrap();
+        let Solutions::Multiple(first, second) = nonogram.solve() else {
+            panic!("expected multiple solutions");
+        };
+        assert_eq!(first.to_string(), "#.\n.#\n");
+        assert_eq!(second.to_string(), ".#\n#.\n");
+
+        let nonogram = Nonogram::parse("2\n0\n\n0\n0\n").unwrap();
+        assert!(matches!(nonogram.solve(), Solutions::None));
+    }
+}
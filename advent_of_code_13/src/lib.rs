@@ -0,0 +1,100 @@
+use anyhow::{anyhow, Result};
+use common::{grid::Grid, parse::ParseError, Solution};
+
+struct Map(Grid<char>);
+
+impl Map {
+    /// Summary of the line of reflection, `None` if the map has none
+    fn get_symmetry(&self, has_smudge: bool) -> Option<usize> {
+        // a vertical line of reflection is a horizontal one of the transposed map
+        if let Some(columns) = Self::find_reflection(&self.0.transpose(), has_smudge) {
+            return Some(columns);
+        }
+
+        Self::find_reflection(&self.0, has_smudge).map(|rows| rows * 100)
+    }
+
+    /// Returns the number of rows above the horizontal line of reflection
+    fn find_reflection(grid: &Grid<char>, has_smudge: bool) -> Option<usize> {
+        let smudges = if has_smudge { 1 } else { 0 };
+        (1..grid.height()).find(|&row| {
+            let differences = (0..row)
+                .rev()
+                .zip(row..grid.height())
+                .map(|(top, bottom)| {
+                    grid.row(top)
+                        .iter()
+                        .zip(grid.row(bottom))
+                        .filter(|(top, bottom)| top != bottom)
+                        .count()
+                })
+                .sum::<usize>();
+            differences == smudges
+        })
+    }
+}
+
+/// Patterns of ash and rocks in the valley of mirrors
+pub struct Patterns(Vec<Map>);
+
+impl Solution for Patterns {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        let maps = input
+            .split("\n\n")
+            .map(|map| {
+                let grid = Grid::parse_with(map, |c| match c {
+                    '#' | '.' => Ok(c),
+                    _ => Err("invalid terrain"),
+                });
+                let pattern = grid.map(Map).map_err(|error| error.within(input, map))?;
+                if pattern.get_symmetry(false).is_none() {
+                    let first_line = map.lines().next().unwrap_or(map);
+                    return Err(ParseError::at(input, first_line, "pattern has no mirror"));
+                }
+                Ok(pattern)
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self(maps))
+    }
+
+    fn part1(&self) -> Result<usize> {
+        // parsing made sure each pattern has a mirror
+        Ok(self.0.iter().filter_map(|m| m.get_symmetry(false)).sum())
+    }
+
+    fn part2(&self) -> Result<usize> {
+        self.0
+            .iter()
+            .enumerate()
+            .map(|(index, map)| {
+                map.get_symmetry(true)
+                    .ok_or_else(|| anyhow!("pattern {} has no mirror with a smudge", index + 1))
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<()> {
+        let patterns = Patterns::parse(include_str!("../input_small.txt"))?;
+        assert_eq!(patterns.part1()?, 405);
+        assert_eq!(patterns.part2()?, 400);
+        Ok(())
+    }
+
+    #[test]
+    fn no_mirror() {
+        let Err(error) = Patterns::parse("#.\n..\n\n#.\n.#\n") else {
+            panic!("the first pattern has no mirror");
+        };
+        let error = error.downcast::<ParseError>().unwrap();
+        assert_eq!((error.line, error.column), (1, 1));
+    }
+}
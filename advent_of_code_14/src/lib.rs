@@ -0,0 +1,87 @@
+use anyhow::Result;
+use common::{geometry::Position, grid::Grid, Solution};
+
+const CYCLES: usize = 1_000_000_000;
+
+/// Platform with rounded and cube-shaped rocks
+pub struct Platform(Grid<char>);
+
+impl Solution for Platform {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        let grid = Grid::parse_with(input, |c| match c {
+            'O' | '#' | '.' => Ok(c),
+            _ => Err("invalid rock"),
+        })?;
+        Ok(Self(grid))
+    }
+
+    fn part1(&self) -> Result<usize> {
+        let mut lines = self.0.clone();
+        tilt_north(&mut lines);
+        Ok(calculate_load(&lines))
+    }
+
+    fn part2(&self) -> Result<usize> {
+        let mut lines = self.0.clone();
+
+        // history[i] contains the map after i cycles
+        let mut history = vec![lines.clone()];
+        loop {
+            // tilt north, west, south and east by turning the platform after each tilt
+            for _ in 0..4 {
+                tilt_north(&mut lines);
+                lines = lines.rotate_clockwise();
+            }
+
+            // once a map repeats, the cycles loop forever between the two duplicates
+            if let Some(start) = history.iter().position(|previous| *previous == lines) {
+                let period = history.len() - start;
+                let index = start + (CYCLES - start) % period;
+                return Ok(calculate_load(&history[index]));
+            }
+            history.push(lines.clone());
+        }
+    }
+}
+
+fn tilt_north(lines: &mut Grid<char>) {
+    for x in 0..lines.width() {
+        let mut next_pos = 0;
+        for y in 0..lines.height() {
+            match lines[Position::new(x, y)] {
+                'O' => {
+                    lines[Position::new(x, y)] = '.';
+                    lines[Position::new(x, next_pos)] = 'O';
+                    next_pos += 1;
+                }
+                '#' => next_pos = y + 1,
+                '.' => {}
+                _ => unreachable!("rocks are checked while parsing"),
+            }
+        }
+    }
+}
+
+fn calculate_load(lines: &Grid<char>) -> usize {
+    lines
+        .iter()
+        .filter(|(_, &c)| c == 'O')
+        .map(|(position, _)| lines.height() - position.y)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<()> {
+        let platform = Platform::parse(include_str!("../input_small.txt"))?;
+        assert_eq!(platform.part1()?, 136);
+        assert_eq!(platform.part2()?, 64);
+        Ok(())
+    }
+}
@@ -0,0 +1,137 @@
+use anyhow::Result;
+use common::{
+    parse::{self, ParseError},
+    Solution,
+};
+
+#[derive(Clone, Copy)]
+enum Operation {
+    Equals,
+    Dash,
+}
+
+#[derive(Debug, Clone)]
+struct Lense {
+    label: String,
+    focal_length: usize,
+}
+
+#[derive(Debug, Default)]
+struct FocalBox {
+    lenses: Vec<Lense>,
+}
+
+impl FocalBox {
+    fn remove_lens(&mut self, lense: Lense) {
+        if let Some(index) = self.get_lense_index(&lense) {
+            self.lenses.remove(index);
+        }
+    }
+
+    fn replace_lens(&mut self, lense: Lense) {
+        if let Some(index) = self.get_lense_index(&lense) {
+            self.lenses.get_mut(index).unwrap().focal_length = lense.focal_length;
+        } else {
+            self.lenses.push(lense);
+        }
+    }
+
+    fn get_lense_index(&self, lense: &Lense) -> Option<usize> {
+        self.lenses.iter().position(|l| l.label == lense.label)
+    }
+}
+
+/// Steps of the initialization sequence
+pub struct Sequence {
+    steps: Vec<String>,
+    operations: Vec<(Lense, Operation)>,
+}
+
+impl Solution for Sequence {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        let operations = input
+            .split(',')
+            .map(|step| parse_string(input, step))
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            steps: input.split(',').map(String::from).collect(),
+            operations,
+        })
+    }
+
+    fn part1(&self) -> Result<usize> {
+        let hash: usize = self.steps.iter().map(|step| calculate_hash(step)).sum();
+        Ok(hash)
+    }
+
+    fn part2(&self) -> Result<usize> {
+        Ok(focusing_power(&self.operations))
+    }
+}
+
+fn focusing_power(operations: &[(Lense, Operation)]) -> usize {
+    let mut boxes: [FocalBox; 256] = array_init::array_init(|_: usize| FocalBox::default());
+
+    for (lense, op) in operations {
+        let lense = lense.clone();
+        let hash = calculate_hash(&lense.label);
+        let current = boxes.get_mut(hash).unwrap();
+        match op {
+            Operation::Dash => current.remove_lens(lense),
+            Operation::Equals => current.replace_lens(lense),
+        }
+    }
+
+    boxes
+        .iter()
+        .enumerate()
+        .map(|(i, b)| {
+            b.lenses
+                .iter()
+                .enumerate()
+                .map(|(j, l)| l.focal_length * (i + 1) * (j + 1))
+                .sum::<usize>()
+        })
+        .sum::<usize>()
+}
+
+fn parse_string(input: &str, string: &str) -> Result<(Lense, Operation), ParseError> {
+    if let Some(index) = string.find('=') {
+        let lense = Lense {
+            label: string[..index].to_string(),
+            focal_length: parse::parse::<usize>(input, &string[index + 1..])?,
+        };
+        Ok((lense, Operation::Equals))
+    } else if let Some(index) = string.find('-') {
+        let lense = Lense {
+            label: string[..index].to_string(),
+            focal_length: 0,
+        };
+        Ok((lense, Operation::Dash))
+    } else {
+        Err(ParseError::at(input, string, "expected `=` or `-`"))
+    }
+}
+
+fn calculate_hash(value: &str) -> usize {
+    value
+        .as_bytes()
+        .iter()
+        .fold(0, |acc, curr| ((acc + (*curr as usize)) * 17) % 256)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<()> {
+        let sequence = Sequence::parse(include_str!("../input_small.txt"))?;
+        assert_eq!(sequence.part1()?, 1320);
+        assert_eq!(sequence.part2()?, 145);
+        Ok(())
+    }
+}
@@ -1,11 +1,11 @@
+use anyhow::Result;
 use bitflags::bitflags;
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
+use common::{
+    geometry::{Direction, Position},
+    grid::Grid,
+    Solution,
 };
 
-const DEVELOP: bool = false;
-
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Field {
     Empty,
@@ -15,27 +15,21 @@ enum Field {
     MirrorVertical,
 }
 
-impl From<char> for Field {
-    fn from(value: char) -> Self {
+impl TryFrom<char> for Field {
+    type Error = &'static str;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
-            '.' => Field::Empty,
-            '/' => Field::MirrorSlash,
-            '\\' => Field::MirrorBackslash,
-            '-' => Field::MirrorHorizontal,
-            '|' => Field::MirrorVertical,
-            _ => panic!("Invalid character"),
+            '.' => Ok(Field::Empty),
+            '/' => Ok(Field::MirrorSlash),
+            '\\' => Ok(Field::MirrorBackslash),
+            '-' => Ok(Field::MirrorHorizontal),
+            '|' => Ok(Field::MirrorVertical),
+            _ => Err("invalid field"),
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Direction {
-    Down,
-    Up,
-    Right,
-    Left,
-}
-
 bitflags! {
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
     pub struct VisitedDirection: u8 {
@@ -58,163 +52,102 @@ impl From<Direction> for VisitedDirection {
     }
 }
 
-struct Map(Vec<Vec<Field>>);
+/// Contraption of mirrors and splitters
+pub struct Map(Grid<Field>);
 
 impl Map {
-    fn new(filename: &str) -> Self {
-        let file = File::open(filename).unwrap();
-        let reader = BufReader::new(file);
-        let data = reader
-            .lines()
-            .flatten()
-            .map(|line| line.chars().map(Field::from).collect())
-            .collect();
-        Self(data)
-    }
-
     fn width(&self) -> usize {
-        self.0[0].len()
+        self.0.width()
     }
 
     fn heigth(&self) -> usize {
-        self.0.len()
+        self.0.height()
     }
 
     fn get(&self, beam: &Beam) -> Field {
-        self.0[beam.position.1][beam.position.0]
+        self.0[beam.position]
     }
 
     fn next(&self, current: &Beam, direction: Direction) -> Option<Beam> {
-        match direction {
-            Direction::Right => {
-                if current.position.0 < self.width() - 1 {
-                    Some(Beam::new(
-                        current.position.0 + 1,
-                        current.position.1,
-                        direction,
-                    ))
-                } else {
-                    None
-                }
-            }
-            Direction::Left => {
-                if current.position.0 > 0 {
-                    Some(Beam::new(
-                        current.position.0 - 1,
-                        current.position.1,
-                        direction,
-                    ))
-                } else {
-                    None
-                }
-            }
-            Direction::Down => {
-                if current.position.1 < self.heigth() - 1 {
-                    Some(Beam::new(
-                        current.position.0,
-                        current.position.1 + 1,
-                        direction,
-                    ))
-                } else {
-                    None
-                }
-            }
-            Direction::Up => {
-                if current.position.1 > 0 {
-                    Some(Beam::new(
-                        current.position.0,
-                        current.position.1 - 1,
-                        direction,
-                    ))
-                } else {
-                    None
-                }
-            }
-        }
+        let position = self.0.step(current.position, direction)?;
+        Some(Beam::new(position.x, position.y, direction))
     }
 }
 
-struct Visited(Vec<Vec<VisitedDirection>>);
+struct Visited(Grid<VisitedDirection>);
 
 impl Visited {
     fn new(width: usize, heigth: usize) -> Self {
-        let data = (0..heigth)
-            .map(|_| vec![VisitedDirection::None; width])
-            .collect();
-        Self(data)
+        Self(Grid::filled(width, heigth, VisitedDirection::None))
     }
 
     fn has_visited(&self, beam: &Beam) -> bool {
-        self.0[beam.position.1][beam.position.0] == VisitedDirection::from(beam.direction)
+        self.0[beam.position] == VisitedDirection::from(beam.direction)
     }
 
     fn visit(&mut self, beam: &Beam) {
-        self.0[beam.position.1][beam.position.0].insert(VisitedDirection::from(beam.direction));
+        self.0[beam.position].insert(VisitedDirection::from(beam.direction));
     }
 
     fn count(&self) -> usize {
-        self.0
-            .iter()
-            .map(|row| row.iter().filter(|d| !d.is_empty()).count())
-            .sum()
+        self.0.iter().filter(|(_, d)| !d.is_empty()).count()
     }
 }
 
 #[derive(Debug, Clone, Copy)]
 struct Beam {
-    position: (usize, usize),
+    position: Position,
     direction: Direction,
 }
 
 impl Beam {
     fn new(x: usize, y: usize, direction: Direction) -> Self {
         Self {
-            position: (x, y),
+            position: Position::new(x, y),
             direction,
         }
     }
 }
 
-fn main() {
-    let filename = if DEVELOP {
-        "input_small.txt"
-    } else {
-        "input.txt"
-    };
+impl Solution for Map {
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    {
-        let map = Map::new(filename);
-        println!(
-            "Task 1: {}",
-            calculate_visited_fields(&map, Beam::new(0, 0, Direction::Right))
-        );
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self(Grid::parse(input)?))
     }
-    {
-        let map = Map::new(filename);
 
+    fn part1(&self) -> Result<usize> {
+        Ok(calculate_visited_fields(
+            self,
+            Beam::new(0, 0, Direction::Right),
+        ))
+    }
+
+    fn part2(&self) -> Result<usize> {
         let mut max = 0;
-        for x in 0..map.width() {
+        for x in 0..self.width() {
             max = max.max(calculate_visited_fields(
-                &map,
+                self,
                 Beam::new(x, 0, Direction::Down),
             ));
             max = max.max(calculate_visited_fields(
-                &map,
-                Beam::new(x, map.heigth() - 1, Direction::Up),
+                self,
+                Beam::new(x, self.heigth() - 1, Direction::Up),
             ));
         }
-        for y in 0..map.heigth() {
+        for y in 0..self.heigth() {
             max = max.max(calculate_visited_fields(
-                &map,
+                self,
                 Beam::new(0, y, Direction::Right),
             ));
             max = max.max(calculate_visited_fields(
-                &map,
-                Beam::new(map.width() - 1, y, Direction::Left),
+                self,
+                Beam::new(self.width() - 1, y, Direction::Left),
             ));
         }
 
-        println!("Task 2: {max}");
+        Ok(max)
     }
 }
 
@@ -275,3 +208,22 @@ fn go_to(map: &Map, current: Beam, direction: Direction, visited: &mut Visited)
         visit_field(map, next, visited);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<()> {
+        let map = Map::parse(include_str!("../input_small.txt"))?;
+        assert_eq!(map.part1()?, 46);
+        assert_eq!(map.part2()?, 51);
+        Ok(())
+    }
+
+    #[test]
+    fn empty() {
+        assert!(Map::parse("").is_err());
+        assert!(Map::parse("\n").is_err());
+    }
+}
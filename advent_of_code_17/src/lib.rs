@@ -1,56 +1,30 @@
 use anyhow::Result;
-use priority_queue::DoublePriorityQueue;
-use std::{
-    collections::HashSet,
-    fmt::Debug,
-    fs::File,
-    hash::Hash,
-    io::{BufRead, BufReader},
+use common::{
+    geometry::{Direction, Position},
+    grid::Grid,
+    Solution, Unsolved,
 };
+use priority_queue::DoublePriorityQueue;
+use std::{collections::HashSet, fmt::Debug, hash::Hash};
 
-const DEVELOP: bool = false;
-
-struct Map(Vec<Vec<u8>>);
+/// Heat loss of each city block
+#[derive(Clone)]
+pub struct Map(Grid<u8>);
 
 impl Map {
-    fn new(file: BufReader<File>) -> Self {
-        let data = file
-            .lines()
-            .flatten()
-            .map(|line| {
-                line.chars()
-                    .map(|c| c.to_digit(10).unwrap() as u8)
-                    .collect()
-            })
-            .collect();
-        Self(data)
-    }
-
     fn width(&self) -> usize {
-        self.0[0].len()
+        self.0.width()
     }
 
     fn heigth(&self) -> usize {
-        self.0.len()
+        self.0.height()
     }
 
     fn get(&self, pos: Position) -> u8 {
-        self.0[pos.1][pos.0]
+        self.0[pos]
     }
 }
 
-type Position = (usize, usize);
-
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
-enum Direction {
-    Left,
-    Right,
-    Down,
-    Up,
-}
-
-impl Direction {}
-
 #[derive(Eq)]
 struct Node {
     position: Position,
@@ -61,7 +35,7 @@ struct Node {
 impl Node {
     fn start() -> Self {
         Self {
-            position: (0, 0),
+            position: Position::new(0, 0),
             score: 0,
             directions: Vec::new(),
         }
@@ -122,7 +96,7 @@ impl SearchAStar {
     }
 
     fn search_path(mut self) -> u64 {
-        let destination = (self.map.width() - 1, self.map.heigth() - 1);
+        let destination = Position::new(self.map.width() - 1, self.map.heigth() - 1);
 
         // add starting node at positon 0,0
         self.open_list.push(Node::start(), 0);
@@ -178,39 +152,23 @@ impl SearchAStar {
     }
 
     fn get_successors(&self, current: &Node) -> Vec<Node> {
-        let mut successors = Vec::new();
-
-        if current.position.0 > 0 && current.directions.last() != Some(&Direction::Right) {
-            successors.push((
-                (current.position.0 - 1, current.position.1),
-                Direction::Left,
-            ));
-        }
-        if current.position.0 < self.map.width() - 1
-            && current.directions.last() != Some(&Direction::Left)
-        {
-            successors.push((
-                (current.position.0 + 1, current.position.1),
-                Direction::Right,
-            ));
-        }
-        if current.position.1 > 0 && current.directions.last() != Some(&Direction::Down) {
-            successors.push(((current.position.0, current.position.1 - 1), Direction::Up));
-        }
-        if current.position.1 < self.map.heigth() - 1
-            && current.directions.last() != Some(&Direction::Up)
-        {
-            successors.push((
-                (current.position.0, current.position.1 + 1),
-                Direction::Down,
-            ));
-        }
-
-        successors
-            .iter()
-            .filter(|(_, dir)| self.check_directions(current, *dir))
-            .map(|(pos, dir)| Node::new(*pos, current, self.map.get(*pos) as u64, *dir))
-            .collect()
+        // a crucible never turns around
+        let previous = current
+            .directions
+            .last()
+            .map(|direction| direction.reverse());
+
+        [
+            Direction::Left,
+            Direction::Right,
+            Direction::Up,
+            Direction::Down,
+        ]
+        .into_iter()
+        .filter(|dir| previous != Some(*dir) && self.check_directions(current, *dir))
+        .filter_map(|dir| Some((self.map.0.step(current.position, dir)?, dir)))
+        .map(|(pos, dir)| Node::new(pos, current, self.map.get(pos) as u64, dir))
+        .collect()
     }
 
     fn check_directions(&self, current: &Node, direction: Direction) -> bool {
@@ -218,25 +176,44 @@ impl SearchAStar {
     }
 
     fn get_heuristic_score(&self, current: &Node) -> u64 {
-        (self.map.width() + self.map.heigth() - (current.position.0 + current.position.1 + 2))
-            as u64
+        let destination = Position::new(self.map.width() - 1, self.map.heigth() - 1);
+        current.position.manhattan_distance(destination) as u64
     }
 }
 
-fn main() -> Result<()> {
-    let filename = if DEVELOP {
-        "input_small.txt"
-    } else {
-        "input.txt"
-    };
-    let file = File::open(filename)?;
-    let reader = BufReader::new(file);
+impl Solution for Map {
+    type Answer1 = u64;
+    type Answer2 = Unsolved;
 
-    {
-        let map = Map::new(reader);
-        let search = SearchAStar::new(map);
-        println!("Task 1: {}", search.search_path())
+    fn parse(input: &str) -> Result<Self> {
+        let grid = Grid::parse_with(input, |c| {
+            c.to_digit(10)
+                .map(|heat_loss| heat_loss as u8)
+                .ok_or("expected a digit")
+        })?;
+        Ok(Self(grid))
     }
 
-    Ok(())
+    fn part1(&self) -> Result<u64> {
+        let search = SearchAStar::new(self.clone());
+        Ok(search.search_path())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<()> {
+        let map = Map::parse(include_str!("../input_small.txt"))?;
+        assert_eq!(map.part1()?, 102);
+        Ok(())
+    }
+
+    #[test]
+    fn empty() {
+        assert!(Map::parse("").is_err());
+        assert!(Map::parse("\n").is_err());
+    }
 }
@@ -0,0 +1,268 @@
+use anyhow::Result;
+use common::{
+    geometry::{Direction, Point},
+    parse::{self, ParseError},
+    Solution,
+};
+use std::{
+    cmp::{max, min},
+    collections::{HashMap, HashSet},
+};
+
+fn parse_direction(input: &str, c: &str) -> Result<Direction, ParseError> {
+    match c {
+        "R" => Ok(Direction::Right),
+        "L" => Ok(Direction::Left),
+        "U" => Ok(Direction::Up),
+        "D" => Ok(Direction::Down),
+        _ => Err(ParseError::at(input, c, "invalid direction")),
+    }
+}
+
+fn direction_from_digit(input: &str, c: &str) -> Result<Direction, ParseError> {
+    match c {
+        "0" => Ok(Direction::Right),
+        "2" => Ok(Direction::Left),
+        "3" => Ok(Direction::Up),
+        "1" => Ok(Direction::Down),
+        _ => Err(ParseError::at(input, c, "invalid direction")),
+    }
+}
+
+struct Line {
+    start: i64,
+    end: i64,
+}
+
+impl Line {
+    fn new(start: i64, end: i64) -> Self {
+        Self { start, end }
+    }
+}
+
+struct Lines(HashMap<i64, Vec<Line>>);
+
+impl Lines {
+    fn new() -> Self {
+        Self(HashMap::new())
+    }
+
+    fn push(&mut self, id: i64, mut start: i64, mut end: i64) {
+        (start, end) = (min(start, end), max(start, end));
+
+        let line = Line::new(start, end);
+        if let Some(lines) = self.0.get_mut(&id) {
+            lines.push(line);
+        } else {
+            self.0.insert(id, vec![line]);
+        }
+    }
+}
+
+struct Map {
+    horizontal: Lines,
+    vertical: Lines,
+    x_min: i64,
+    x_max: i64,
+    y_min: i64,
+    y_max: i64,
+}
+
+impl Map {
+    fn new(instructions: &[(Direction, i64)]) -> Self {
+        let mut horizontal = Lines::new();
+        let mut vertical = Lines::new();
+        let mut x_min = 0;
+        let mut x_max = 0;
+        let mut y_min = 0;
+        let mut y_max = 0;
+        let mut current = Point::default();
+
+        for &(direction, count) in instructions {
+            let next = current.step_by(direction, count);
+
+            match direction {
+                Direction::Left | Direction::Right => horizontal.push(current.y, current.x, next.x),
+                Direction::Down | Direction::Up => vertical.push(current.x, current.y, next.y),
+            }
+
+            match direction {
+                Direction::Left => x_min = x_min.min(next.x),
+                Direction::Right => x_max = x_max.max(next.x),
+                Direction::Down => y_max = y_max.max(next.y),
+                Direction::Up => y_min = y_min.min(next.y),
+            }
+
+            current = next;
+        }
+
+        Self {
+            horizontal,
+            vertical,
+            x_min,
+            x_max,
+            y_min,
+            y_max,
+        }
+    }
+
+    fn parse_line(input: &str, line: &str) -> Result<(Direction, i64), ParseError> {
+        let mut items = line.split_whitespace();
+        let direction = items
+            .next()
+            .ok_or_else(|| ParseError::after(input, line, "expected a direction"))?;
+        let count = items
+            .next()
+            .ok_or_else(|| ParseError::after(input, line, "expected a distance"))?;
+        Ok((
+            parse_direction(input, direction)?,
+            parse::parse(input, count)?,
+        ))
+    }
+
+    fn parse_color(input: &str, line: &str) -> Result<(Direction, i64), ParseError> {
+        let (_, color) = parse::split_once(input, line, "#")?;
+        let color = color.strip_suffix(')').unwrap_or(color);
+        if color.len() != 6 || !color.is_ascii() {
+            return Err(ParseError::at(
+                input,
+                color,
+                "expected a color of six hex digits",
+            ));
+        }
+
+        let count = i64::from_str_radix(&color[..5], 16)
+            .map_err(|_| ParseError::at(input, &color[..5], "expected hex digits"))?;
+        let direction = direction_from_digit(input, &color[5..])?;
+        Ok((direction, count))
+    }
+
+    fn contains(&self, position: Point) -> bool {
+        if let Some(horizontal) = self.horizontal.0.get(&position.y) {
+            if horizontal
+                .iter()
+                .any(|line| line.start <= position.x && position.x <= line.end)
+            {
+                return true;
+            }
+        }
+
+        if let Some(vertical) = self.vertical.0.get(&position.x) {
+            if vertical
+                .iter()
+                .any(|line| line.start <= position.y && position.y <= line.end)
+            {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn is_valid(&self, position: Point) -> bool {
+        self.x_min <= position.x
+            && position.x <= self.x_max
+            && self.y_min <= position.y
+            && position.y <= self.y_max
+    }
+}
+
+struct DigPlan {
+    map: Map,
+}
+
+impl DigPlan {
+    fn new(map: Map) -> Self {
+        Self { map }
+    }
+
+    fn area(&self) -> usize {
+        let mut flood_map = HashSet::new();
+        for x in self.map.x_min..self.map.x_max {
+            self.flood(Point::new(x, self.map.y_max), &mut flood_map);
+            self.flood(Point::new(x, self.map.y_min), &mut flood_map);
+        }
+        for y in self.map.y_min..self.map.y_max {
+            self.flood(Point::new(self.map.x_min, y), &mut flood_map);
+            self.flood(Point::new(self.map.x_max, y), &mut flood_map);
+        }
+
+        let full_area =
+            (self.map.x_max - self.map.x_min + 1) * (self.map.y_max - self.map.y_min + 1);
+        (full_area as usize) - flood_map.len()
+    }
+
+    fn flood(&self, current: Point, flood_map: &mut HashSet<Point>) {
+        // check if:
+        //  - position is not valid (outside the map) or
+        //  - position if already flooded or
+        //  - reached a wall
+        if flood_map.contains(&current) || !self.map.is_valid(current) || self.map.contains(current)
+        {
+            return;
+        }
+
+        flood_map.insert(current);
+
+        self.flood(current.step(Direction::Right), flood_map);
+        self.flood(current.step(Direction::Left), flood_map);
+        self.flood(current.step(Direction::Up), flood_map);
+        self.flood(current.step(Direction::Down), flood_map);
+    }
+}
+
+/// Dig plan of the lagoon, read both from the instructions and from the color codes
+pub struct Instructions {
+    plan: Vec<(Direction, i64)>,
+    color_plan: Vec<(Direction, i64)>,
+}
+
+impl Solution for Instructions {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            plan: input
+                .lines()
+                .map(|line| Map::parse_line(input, line))
+                .collect::<Result<_, _>>()?,
+            color_plan: input
+                .lines()
+                .map(|line| Map::parse_color(input, line))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn part1(&self) -> Result<usize> {
+        let plan = DigPlan::new(Map::new(&self.plan));
+        Ok(plan.area())
+    }
+
+    fn part2(&self) -> Result<usize> {
+        let plan = DigPlan::new(Map::new(&self.color_plan));
+        Ok(plan.area())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<()> {
+        let instructions = Instructions::parse(include_str!("../input_small.txt"))?;
+        assert_eq!(instructions.part1()?, 62);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_direction() {
+        let Err(error) = Instructions::parse("R 6 (#70c710)\nX 5 (#0dc571)\n") else {
+            panic!("the direction is invalid");
+        };
+        let error = error.downcast::<ParseError>().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.text, "X");
+    }
+}
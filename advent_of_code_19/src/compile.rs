@@ -0,0 +1,223 @@
+use crate::{
+    too_many_combinations, Decision, Part, PartRange, Rule, RuleResult, System, Workflows,
+};
+use anyhow::Result;
+use std::{fmt::Write, ops::RangeInclusive};
+
+/// The workflows with all labels inlined, starting at `in`.
+///
+/// Decisions that are always or never true for the ratings the tree was compiled for are left
+/// out, as are decisions whose outcome does not matter.
+pub struct DecisionTree {
+    ratings: PartRange,
+    root: Node,
+}
+
+#[derive(PartialEq, Eq)]
+enum Node {
+    /// Whether the part is accepted
+    Leaf(bool),
+    Branch {
+        /// The condition, its result is replaced by `then`
+        decision: Decision,
+        then: Box<Node>,
+        otherwise: Box<Node>,
+    },
+}
+
+impl DecisionTree {
+    /// Whether the part is accepted, its ratings must be within the ratings of the tree
+    pub(crate) fn accepts(&self, part: &Part) -> bool {
+        let mut node = &self.root;
+        loop {
+            match node {
+                Node::Leaf(accepted) => return *accepted,
+                Node::Branch {
+                    decision,
+                    then,
+                    otherwise,
+                } => {
+                    node = if decision.is_true(part.get(decision.category)) {
+                        then
+                    } else {
+                        otherwise
+                    }
+                }
+            }
+        }
+    }
+
+    /// Disjoint ranges of all accepted parts
+    pub fn accepted(&self) -> Vec<PartRange> {
+        let mut accepted = Vec::new();
+        Self::collect_accepted(&self.root, self.ratings, &mut accepted);
+        accepted
+    }
+
+    fn collect_accepted(node: &Node, mut parts: PartRange, accepted: &mut Vec<PartRange>) {
+        match node {
+            Node::Leaf(true) => accepted.push(parts),
+            Node::Leaf(false) => {}
+            Node::Branch {
+                decision,
+                then,
+                otherwise,
+            } => {
+                let matching = decision.split(&mut parts);
+                Self::collect_accepted(then, matching, accepted);
+                Self::collect_accepted(otherwise, parts, accepted);
+            }
+        }
+    }
+
+    /// Number of accepted parts
+    pub fn combinations(&self) -> Result<usize> {
+        self.accepted()
+            .iter()
+            .try_fold(0usize, |sum, parts| sum.checked_add(parts.combinations()?))
+            .ok_or_else(too_many_combinations)
+    }
+
+    /// Number of decisions left in the tree
+    pub fn decisions(&self) -> usize {
+        fn count(node: &Node) -> usize {
+            match node {
+                Node::Leaf(_) => 0,
+                Node::Branch {
+                    then, otherwise, ..
+                } => 1 + count(then) + count(otherwise),
+            }
+        }
+        count(&self.root)
+    }
+
+    /// Renders the tree in the Graphviz DOT language
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph workflows {\n    node [fontname=monospace];\n");
+        let mut next_id = 0;
+        Self::write_dot(&self.root, &mut next_id, &mut dot);
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Writes the node and its children, returns the id of the node
+    fn write_dot(node: &Node, next_id: &mut usize, dot: &mut String) -> usize {
+        let id = *next_id;
+        *next_id += 1;
+        match node {
+            Node::Leaf(true) => writeln!(
+                dot,
+                "    n{id} [label=\"A\", shape=box, style=filled, fillcolor=palegreen];"
+            ),
+            Node::Leaf(false) => writeln!(
+                dot,
+                "    n{id} [label=\"R\", shape=box, style=filled, fillcolor=lightcoral];"
+            ),
+            Node::Branch {
+                decision,
+                then,
+                otherwise,
+            } => {
+                writeln!(
+                    dot,
+                    "    n{id} [label=\"{}{}{}\", shape=diamond];",
+                    decision.category, decision.operator, decision.value
+                )
+                .unwrap();
+                let then = Self::write_dot(then, next_id, dot);
+                let otherwise = Self::write_dot(otherwise, next_id, dot);
+                writeln!(
+                    dot,
+                    "    n{id} -> n{then} [label=\"yes\"];\n    \
+                     n{id} -> n{otherwise} [label=\"no\", style=dashed];"
+                )
+            }
+        }
+        .unwrap();
+        id
+    }
+}
+
+impl Workflows {
+    fn compile_result(&self, result: &RuleResult, parts: PartRange) -> Node {
+        match result {
+            RuleResult::Accepted => Node::Leaf(true),
+            RuleResult::Rejected => Node::Leaf(false),
+            RuleResult::GoTo(label) => self.compile_rules(&self.0[label].0, parts),
+        }
+    }
+
+    /// Compiles the rules for the parts reaching the first of them
+    fn compile_rules(&self, rules: &[Rule], mut parts: PartRange) -> Node {
+        // parts passing all rules are not accepted, like in `Workflow::apply_range`
+        let Some((rule, rest)) = rules.split_first() else {
+            return Node::Leaf(false);
+        };
+
+        let decision = match rule {
+            Rule::Result(result) => return self.compile_result(result, parts),
+            Rule::Decision(decision) => decision,
+        };
+        let matching = decision.split(&mut parts);
+        if matching.is_empty() {
+            return self.compile_rules(rest, parts);
+        }
+        if parts.is_empty() {
+            return self.compile_result(&decision.result, matching);
+        }
+
+        let then = self.compile_result(&decision.result, matching);
+        let otherwise = self.compile_rules(rest, parts);
+        if then == otherwise {
+            return then;
+        }
+        Node::Branch {
+            decision: decision.clone(),
+            then: Box::new(then),
+            otherwise: Box::new(otherwise),
+        }
+    }
+}
+
+impl System {
+    /// Compiles the workflows for parts with each rating within the given range
+    pub fn compile(&self, ratings: RangeInclusive<i32>) -> DecisionTree {
+        let ratings = PartRange::new(ratings);
+        DecisionTree {
+            ratings,
+            root: self
+                .workflows
+                .compile_result(&RuleResult::GoTo(String::from("in")), ratings),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Parser, RATINGS};
+
+    #[test]
+    fn example() {
+        let system = Parser::parse(include_str!("../input_small.txt")).unwrap();
+        let tree = system.compile(RATINGS);
+        for part in &system.machine_parts.0 {
+            let trace = system.workflows.explain(part);
+            assert_eq!(tree.accepts(part), trace.is_accepted());
+        }
+        assert_eq!(tree.combinations().unwrap(), 167409079868000);
+    }
+
+    #[test]
+    fn simplify() {
+        // `a>5` always leads to rejection, `m<10` is always true within the ratings
+        let system = Parser::parse("in{a>5:ab,m<10:A,R}\nab{x<3:R,R}\n").unwrap();
+        let tree = system.compile(1..=9);
+        assert_eq!(tree.decisions(), 1);
+        assert_eq!(tree.accepted().len(), 1);
+        assert_eq!(
+            tree.accepted()[0].to_string(),
+            "{x=1..=9,m=1..=9,a=1..=5,s=1..=9}"
+        );
+        assert!(tree.to_dot().contains("[label=\"a>5\", shape=diamond]"));
+    }
+}
@@ -0,0 +1,147 @@
+use crate::{Decision, Part, Rule, RuleResult, System, Workflows};
+use std::fmt;
+
+/// Path of a part through the workflows, from `in` until it is accepted or rejected
+pub struct Trace {
+    part: Part,
+    steps: Vec<Step>,
+    result: RuleResult,
+}
+
+/// Rule of a workflow that sent the part on
+struct Step {
+    workflow: String,
+    /// Index of the rule within the workflow, `None` if no rule applied and the part was rejected
+    rule: Option<usize>,
+    /// The decision that was true, `None` if the fallback rule applied
+    decision: Option<Decision>,
+    result: RuleResult,
+}
+
+impl Trace {
+    pub fn is_accepted(&self) -> bool {
+        self.result == RuleResult::Accepted
+    }
+
+    /// Labels of the workflows the part went through
+    pub fn workflows(&self) -> Vec<&str> {
+        self.steps
+            .iter()
+            .map(|step| step.workflow.as_str())
+            .collect()
+    }
+}
+
+impl fmt::Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} -> {}",
+            self.part,
+            self.workflows().join(" -> "),
+            self.result
+        )?;
+        for step in &self.steps {
+            match (step.rule, &step.decision) {
+                (Some(rule), Some(decision)) => {
+                    write!(f, "\n  {}, rule {}: {decision}", step.workflow, rule + 1)?
+                }
+                (Some(rule), None) => write!(
+                    f,
+                    "\n  {}, rule {}: {}",
+                    step.workflow,
+                    rule + 1,
+                    step.result
+                )?,
+                (None, _) => write!(f, "\n  {}, no rule: {}", step.workflow, step.result)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Workflows {
+    /// Sorts the part, recording the rule that applied in each workflow
+    pub(crate) fn explain(&self, part: &Part) -> Trace {
+        let mut steps = Vec::new();
+        let mut result = RuleResult::GoTo(String::from("in"));
+        while let RuleResult::GoTo(label) = result {
+            let workflow = self.0.get(&label).unwrap();
+            // parts no rule applies to are rejected, like in `compile_rules`
+            let (rule, next) = workflow
+                .0
+                .iter()
+                .enumerate()
+                .find_map(|(index, rule)| rule.apply(part).map(|next| (index, next)))
+                .map_or((None, RuleResult::Rejected), |(rule, next)| {
+                    (Some(rule), next)
+                });
+
+            steps.push(Step {
+                workflow: label,
+                rule,
+                decision: rule.and_then(|rule| rule_decision(&workflow.0[rule])),
+                result: next.clone(),
+            });
+            result = next;
+        }
+
+        Trace {
+            part: part.clone(),
+            steps,
+            result,
+        }
+    }
+}
+
+fn rule_decision(rule: &Rule) -> Option<Decision> {
+    match rule {
+        Rule::Decision(decision) => Some(decision.clone()),
+        Rule::Result(_) => None,
+    }
+}
+
+impl System {
+    /// Paths of all machine parts through the workflows, in the order of the input
+    pub fn explain(&self) -> Vec<Trace> {
+        self.machine_parts
+            .0
+            .iter()
+            .map(|part| self.workflows.explain(part))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Parser;
+
+    #[test]
+    fn example() {
+        let system = Parser::parse(include_str!("../input_small.txt")).unwrap();
+        let traces = system.explain();
+        assert_eq!(traces[0].workflows(), ["in", "qqz", "qs", "lnx"]);
+        assert!(traces[0].is_accepted());
+        assert_eq!(
+            traces[0].to_string(),
+            "{x=787,m=2655,a=1222,s=2876}: in -> qqz -> qs -> lnx -> A\n  \
+             in, rule 2: qqz\n  \
+             qqz, rule 1: s>2770:qs\n  \
+             qs, rule 2: lnx\n  \
+             lnx, rule 1: m>1548:A"
+        );
+        assert_eq!(traces[1].workflows(), ["in", "px", "rfg", "gd"]);
+        assert!(!traces[1].is_accepted());
+    }
+
+    #[test]
+    fn no_rule_applies() {
+        let system = Parser::parse("in{x<5:A}\n\n{x=7,m=1,a=1,s=1}\n").unwrap();
+        let traces = system.explain();
+        assert!(!traces[0].is_accepted());
+        assert_eq!(
+            traces[0].to_string(),
+            "{x=7,m=1,a=1,s=1}: in -> R\n  in, no rule: R"
+        );
+    }
+}
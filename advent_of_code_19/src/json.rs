@@ -0,0 +1,80 @@
+use crate::{MachineParts, Part, System, Workflow, Workflows};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// JSON document of a system, the workflows are sorted by label
+#[derive(Serialize)]
+struct Document<'a> {
+    workflows: BTreeMap<&'a str, &'a Workflow>,
+    parts: &'a [Part],
+}
+
+#[derive(Deserialize)]
+struct OwnedDocument {
+    workflows: HashMap<String, Workflow>,
+    #[serde(default)]
+    parts: Vec<Part>,
+}
+
+impl System {
+    /// Writes the workflows and parts as JSON, e.g.
+    /// `{"workflows":{"in":[{"category":"s","operator":"<","value":1351,"result":"px"},"qqz"]},"parts":[{"x":787,"m":2655,"a":1222,"s":2876}]}`
+    pub fn to_json(&self) -> String {
+        let document = Document {
+            workflows: self
+                .workflows
+                .0
+                .iter()
+                .map(|(label, workflow)| (label.as_str(), workflow))
+                .collect(),
+            parts: &self.machine_parts.0,
+        };
+        serde_json::to_string_pretty(&document).unwrap()
+    }
+
+    /// Reads the workflows and parts from JSON written by [`System::to_json`]
+    pub fn from_json(json: &str) -> Result<Self> {
+        let document: OwnedDocument = serde_json::from_str(json).context("invalid JSON")?;
+        System {
+            workflows: Workflows(document.workflows),
+            machine_parts: MachineParts(document.parts),
+        }
+        .check()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::System;
+    use anyhow::Result;
+    use common::Solution;
+
+    #[test]
+    fn round_trip() -> Result<()> {
+        let input = include_str!("../input_small.txt");
+        let system = System::parse(input)?;
+
+        // the syntax is kept, only the workflows are sorted
+        let text = system.to_string();
+        let mut lines = text.lines().collect::<Vec<_>>();
+        let mut expected = input.lines().collect::<Vec<_>>();
+        lines.sort();
+        expected.sort();
+        assert_eq!(lines, expected);
+        assert_eq!(System::parse(&text)?.to_string(), text);
+
+        let json = system.to_json();
+        let system = System::from_json(&json)?;
+        assert_eq!(system.to_json(), json);
+        assert_eq!(system.to_string(), text);
+        assert_eq!(system.part1()?, 19114);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_json() {
+        assert!(System::from_json(r#"{"workflows":{"in":["ab"]}}"#).is_err());
+        assert!(System::from_json(r#"{"workflows":{"in":[{"category":"y"}]}}"#).is_err());
+    }
+}
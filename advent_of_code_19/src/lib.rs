@@ -0,0 +1,712 @@
+mod compile;
+mod explain;
+mod json;
+mod validate;
+
+pub use compile::DecisionTree;
+pub use explain::Trace;
+pub use validate::Issue;
+
+use anyhow::{anyhow, bail, Result};
+use common::{
+    parse::{self, ParseError},
+    Solution,
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fmt, ops::RangeInclusive};
+
+struct Parser<'a> {
+    input: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    fn parse(input: &'a str) -> Result<System, ParseError> {
+        let parser = Self { input };
+        let mut workflows = Workflows::new();
+        let mut machine_parts = MachineParts::new();
+        let mut found_empty = false;
+        for line in input.lines() {
+            if line.is_empty() {
+                found_empty = true;
+            } else if found_empty {
+                machine_parts.0.push(parser.parse_part(line)?);
+            } else {
+                let (id, workflow) = parser.parse_workflow(line)?;
+                workflows.0.insert(id, workflow);
+            }
+        }
+        Ok(System {
+            workflows,
+            machine_parts,
+        })
+    }
+
+    fn parse_workflow(&self, line: &str) -> Result<(String, Workflow), ParseError> {
+        let (id, rules) = parse::split_once(self.input, line, "{")?;
+        let rules = rules
+            .strip_suffix('}')
+            .ok_or_else(|| ParseError::after(self.input, line, "expected `}`"))?;
+
+        let id = id.to_owned();
+
+        let mut workflow = Vec::new();
+        for rule in rules.split(',') {
+            if let Some(rule) = self.parse_rule(rule)? {
+                workflow.push(rule);
+            } else {
+                let result = RuleResult::from(rule);
+                workflow.push(Rule::Result(result));
+            }
+        }
+
+        Ok((id, Workflow(workflow)))
+    }
+
+    fn parse_rule(&self, line: &str) -> Result<Option<Rule>, ParseError> {
+        if let Some(Ok(operator)) = line.get(1..2).map(Operator::try_from) {
+            let (value, result) = parse::split_once(self.input, &line[2..], ":")?;
+            return Ok(Some(Rule::Decision(Decision {
+                category: self.parse_category(&line[0..1])?,
+                operator,
+                value: parse::parse(self.input, value)?,
+                result: RuleResult::from(result),
+            })));
+        }
+        Ok(None)
+    }
+
+    fn parse_part(&self, line: &str) -> Result<Part, ParseError> {
+        let ratings = line
+            .strip_prefix('{')
+            .and_then(|line| line.strip_suffix('}'))
+            .ok_or_else(|| ParseError::at(self.input, line, "expected `{<ratings>}`"))?;
+
+        let mut machine_part = Part::new();
+        let mut rated = Vec::new();
+        for rating in ratings.split(',') {
+            let (text, value) = parse::split_once(self.input, rating, "=")?;
+            let category = self.parse_category(text)?;
+            if rated.contains(&category) {
+                return Err(ParseError::at(self.input, text, "category is rated twice"));
+            }
+            rated.push(category);
+            machine_part.set(category, parse::parse(self.input, value)?)
+        }
+        if let Some(missing) = Category::ALL.iter().find(|c| !rated.contains(c)) {
+            let message = format!("expected a rating of `{missing}`");
+            return Err(ParseError::at(self.input, line, message));
+        }
+        Ok(machine_part)
+    }
+
+    fn parse_category(&self, text: &str) -> Result<Category, ParseError> {
+        Category::try_from(text).map_err(|_| ParseError::at(self.input, text, "invalid category"))
+    }
+}
+
+struct Workflows(HashMap<String, Workflow>);
+
+impl Workflows {
+    fn new() -> Self {
+        Self(HashMap::new())
+    }
+
+    /// Number of combinations of ratings within the range that are accepted, `None` if there are
+    /// more than fit into a `usize`
+    fn apply_range(&self, parts: PartRange) -> Option<usize> {
+        self.apply_result_range(&RuleResult::GoTo(String::from("in")), parts)
+    }
+
+    fn apply_result_range(&self, result: &RuleResult, parts: PartRange) -> Option<usize> {
+        match result {
+            RuleResult::Accepted => parts.combinations(),
+            RuleResult::Rejected => Some(0),
+            RuleResult::GoTo(label) => {
+                let workflow = self.0.get(label).unwrap();
+                workflow.apply_range(self, parts)
+            }
+        }
+    }
+}
+
+/// One workflow per line, sorted by label
+impl fmt::Display for Workflows {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut labels = self.0.keys().collect::<Vec<_>>();
+        labels.sort();
+        for label in labels {
+            writeln!(f, "{label}{}", self.0[label])?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+struct Workflow(Vec<Rule>);
+
+impl Workflow {
+    fn apply_range(&self, workflows: &Workflows, mut parts: PartRange) -> Option<usize> {
+        let mut accepted = 0;
+        for rule in self.0.iter() {
+            if parts.is_empty() {
+                break;
+            }
+
+            // the parts not matching the rule continue with the next one
+            let (matching, result) = rule.apply_range(&mut parts);
+            accepted = workflows
+                .apply_result_range(&result, matching)?
+                .checked_add(accepted)?;
+        }
+        Some(accepted)
+    }
+}
+
+impl fmt::Display for Workflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rules = self.0.iter().map(Rule::to_string).collect::<Vec<_>>();
+        write!(f, "{{{}}}", rules.join(","))
+    }
+}
+
+/// A decision, or the result if none of the decisions before it were true.
+///
+/// In JSON a result is written as its label only.
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum Rule {
+    Decision(Decision),
+    Result(RuleResult),
+}
+
+impl Rule {
+    fn apply(&self, part: &Part) -> Option<RuleResult> {
+        match &self {
+            Rule::Decision(decision) => decision.decide(part),
+            Rule::Result(result) => Some(result.clone()),
+        }
+    }
+
+    /// Splits off the parts matching the rule, the remaining ones are left in `part`
+    fn apply_range(&self, part: &mut PartRange) -> (PartRange, RuleResult) {
+        match &self {
+            Rule::Decision(decision) => (decision.split(part), decision.result.clone()),
+            Rule::Result(result) => (std::mem::replace(part, PartRange::empty()), result.clone()),
+        }
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rule::Decision(decision) => write!(f, "{decision}"),
+            Rule::Result(result) => write!(f, "{result}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Decision {
+    category: Category,
+    operator: Operator,
+    value: i32,
+    result: RuleResult,
+}
+
+impl Decision {
+    fn is_true(&self, part_value: i32) -> bool {
+        match self.operator {
+            Operator::Less => part_value < self.value,
+            Operator::More => part_value > self.value,
+        }
+    }
+
+    fn decide(&self, part: &Part) -> Option<RuleResult> {
+        if self.is_true(part.get(self.category)) {
+            Some(self.result.clone())
+        } else {
+            None
+        }
+    }
+
+    /// Splits off the parts the decision is true for
+    fn split(&self, parts: &mut PartRange) -> PartRange {
+        let ratings = parts.get_mut(self.category);
+        let (matching, remaining) = match self.operator {
+            Operator::Less => ratings.split_at(self.value),
+            // no rating is more than the largest one
+            Operator::More => match self.value.checked_add(1) {
+                Some(value) => {
+                    let (remaining, matching) = ratings.split_at(value);
+                    (matching, remaining)
+                }
+                None => (Interval::EMPTY, *ratings),
+            },
+        };
+        *ratings = remaining;
+
+        let mut matching_parts = *parts;
+        *matching_parts.get_mut(self.category) = matching;
+        matching_parts
+    }
+}
+
+impl fmt::Display for Decision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}{}{}:{}",
+            self.category, self.operator, self.value, self.result
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum Operator {
+    #[serde(rename = "<")]
+    Less,
+    #[serde(rename = ">")]
+    More,
+}
+
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operator::Less => write!(f, "<"),
+            Operator::More => write!(f, ">"),
+        }
+    }
+}
+
+impl TryFrom<&str> for Operator {
+    type Error = ();
+
+    fn try_from(op: &str) -> Result<Self, Self::Error> {
+        match op {
+            "<" => Ok(Operator::Less),
+            ">" => Ok(Operator::More),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+enum RuleResult {
+    GoTo(String),
+    Accepted,
+    Rejected,
+}
+
+impl From<&str> for RuleResult {
+    fn from(value: &str) -> Self {
+        match value {
+            "A" => RuleResult::Accepted,
+            "R" => RuleResult::Rejected,
+            _ => Self::GoTo(String::from(value)),
+        }
+    }
+}
+
+impl From<String> for RuleResult {
+    fn from(value: String) -> Self {
+        RuleResult::from(value.as_str())
+    }
+}
+
+impl From<RuleResult> for String {
+    fn from(value: RuleResult) -> Self {
+        value.to_string()
+    }
+}
+
+impl fmt::Display for RuleResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RuleResult::GoTo(label) => write!(f, "{label}"),
+            RuleResult::Accepted => write!(f, "A"),
+            RuleResult::Rejected => write!(f, "R"),
+        }
+    }
+}
+
+struct MachineParts(Vec<Part>);
+
+impl MachineParts {
+    fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Smallest range containing the ratings of all parts
+    fn ratings(&self) -> RangeInclusive<i32> {
+        let ratings = self
+            .0
+            .iter()
+            .flat_map(|part| Category::ALL.map(|category| part.get(category)));
+        let min = ratings.clone().min().unwrap_or(*RATINGS.start());
+        let max = ratings.max().unwrap_or(*RATINGS.end());
+        min..=max
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum Category {
+    #[serde(rename = "x")]
+    CoolLooking,
+    #[serde(rename = "m")]
+    Musical,
+    #[serde(rename = "a")]
+    Aerodynamic,
+    #[serde(rename = "s")]
+    Shiny,
+}
+
+impl Category {
+    const ALL: [Category; 4] = [
+        Category::CoolLooking,
+        Category::Musical,
+        Category::Aerodynamic,
+        Category::Shiny,
+    ];
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Category::CoolLooking => 'x',
+            Category::Musical => 'm',
+            Category::Aerodynamic => 'a',
+            Category::Shiny => 's',
+        };
+        write!(f, "{c}")
+    }
+}
+
+impl TryFrom<&str> for Category {
+    type Error = ();
+
+    fn try_from(c: &str) -> Result<Self, Self::Error> {
+        match c {
+            "x" => Ok(Category::CoolLooking),
+            "m" => Ok(Category::Musical),
+            "a" => Ok(Category::Aerodynamic),
+            "s" => Ok(Category::Shiny),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Part {
+    #[serde(rename = "x")]
+    cool_looking: i32,
+    #[serde(rename = "m")]
+    musical: i32,
+    #[serde(rename = "a")]
+    aerodynamic: i32,
+    #[serde(rename = "s")]
+    shiny: i32,
+}
+
+impl Part {
+    fn new() -> Self {
+        Self {
+            cool_looking: 0,
+            musical: 0,
+            aerodynamic: 0,
+            shiny: 0,
+        }
+    }
+
+    fn set(&mut self, category: Category, value: i32) {
+        match category {
+            Category::CoolLooking => self.cool_looking = value,
+            Category::Musical => self.musical = value,
+            Category::Aerodynamic => self.aerodynamic = value,
+            Category::Shiny => self.shiny = value,
+        }
+    }
+
+    fn get(&self, category: Category) -> i32 {
+        match category {
+            Category::CoolLooking => self.cool_looking,
+            Category::Musical => self.musical,
+            Category::Aerodynamic => self.aerodynamic,
+            Category::Shiny => self.shiny,
+        }
+    }
+
+    fn rating(&self) -> i32 {
+        self.cool_looking + self.musical + self.aerodynamic + self.shiny
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ratings = Category::ALL
+            .iter()
+            .map(|&category| format!("{category}={}", self.get(category)))
+            .collect::<Vec<_>>();
+        write!(f, "{{{}}}", ratings.join(","))
+    }
+}
+
+/// Ratings of the parts in the puzzle
+pub const RATINGS: RangeInclusive<i32> = 1..=4000;
+
+/// Inclusive interval of ratings, empty if its start is after its end
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Interval {
+    start: i32,
+    end: i32,
+}
+
+impl Interval {
+    const EMPTY: Self = Self { start: 1, end: 0 };
+
+    fn new(start: i32, end: i32) -> Self {
+        Self { start, end }
+    }
+
+    fn len(&self) -> usize {
+        if self.start > self.end {
+            0
+        } else {
+            (i64::from(self.end) - i64::from(self.start)) as usize + 1
+        }
+    }
+
+    /// Splits into the ratings below the value and the ones from the value on
+    fn split_at(self, value: i32) -> (Interval, Interval) {
+        // no rating is below the smallest one
+        let below = match value.checked_sub(1) {
+            Some(last) => Interval::new(self.start, self.end.min(last)),
+            None => Interval::EMPTY,
+        };
+        let above = Interval::new(self.start.max(value), self.end);
+        (below, above)
+    }
+}
+
+/// All parts with the ratings of each category within an interval
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PartRange {
+    cool_looking: Interval,
+    musical: Interval,
+    aerodynamic: Interval,
+    shiny: Interval,
+}
+
+impl PartRange {
+    fn new(ratings: RangeInclusive<i32>) -> Self {
+        let ratings = Interval::new(*ratings.start(), *ratings.end());
+        PartRange {
+            cool_looking: ratings,
+            musical: ratings,
+            aerodynamic: ratings,
+            shiny: ratings,
+        }
+    }
+
+    fn empty() -> Self {
+        PartRange {
+            cool_looking: Interval::EMPTY,
+            musical: Interval::EMPTY,
+            aerodynamic: Interval::EMPTY,
+            shiny: Interval::EMPTY,
+        }
+    }
+
+    fn get(&self, category: Category) -> Interval {
+        match category {
+            Category::CoolLooking => self.cool_looking,
+            Category::Musical => self.musical,
+            Category::Aerodynamic => self.aerodynamic,
+            Category::Shiny => self.shiny,
+        }
+    }
+
+    fn get_mut(&mut self, category: Category) -> &mut Interval {
+        match category {
+            Category::CoolLooking => &mut self.cool_looking,
+            Category::Musical => &mut self.musical,
+            Category::Aerodynamic => &mut self.aerodynamic,
+            Category::Shiny => &mut self.shiny,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        Category::ALL
+            .iter()
+            .any(|&category| self.get(category).len() == 0)
+    }
+
+    /// Number of parts in the range, `None` if there are more than fit into a `usize`
+    pub fn combinations(&self) -> Option<usize> {
+        Category::ALL
+            .iter()
+            .try_fold(1usize, |combinations, &category| {
+                combinations.checked_mul(self.get(category).len())
+            })
+    }
+}
+
+impl fmt::Display for PartRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ratings = Category::ALL
+            .iter()
+            .map(|&category| {
+                let interval = self.get(category);
+                format!("{category}={}..={}", interval.start, interval.end)
+            })
+            .collect::<Vec<_>>();
+        write!(f, "{{{}}}", ratings.join(","))
+    }
+}
+
+fn too_many_combinations() -> anyhow::Error {
+    anyhow!("there are more than {} combinations", usize::MAX)
+}
+
+/// Workflows of the part sorting system and the machine parts to sort
+pub struct System {
+    workflows: Workflows,
+    machine_parts: MachineParts,
+}
+
+impl System {
+    /// Reads the workflows and parts without rejecting fatal issues, to report them with
+    /// [`System::issues`]. Sorting parts with fatal issues fails or never ends.
+    pub fn parse_unchecked(input: &str) -> Result<Self, ParseError> {
+        Parser::parse(input)
+    }
+
+    /// Problems of the workflows for parts with each rating within the range, see [`Issue`]
+    pub fn issues(&self, ratings: RangeInclusive<i32>) -> Vec<Issue> {
+        self.workflows.validate(ratings)
+    }
+
+    /// Number of combinations of ratings that are accepted, each rating within the given range
+    pub fn combinations(&self, ratings: RangeInclusive<i32>) -> Result<usize> {
+        self.workflows
+            .apply_range(PartRange::new(ratings))
+            .ok_or_else(too_many_combinations)
+    }
+
+    /// Rejects workflows with fatal issues, sorting a part with them fails or never ends
+    fn check(self) -> Result<Self> {
+        let fatal = self
+            .issues(RATINGS)
+            .into_iter()
+            .filter(Issue::is_fatal)
+            .map(|issue| issue.to_string())
+            .collect::<Vec<_>>();
+        if !fatal.is_empty() {
+            bail!("invalid workflows: {}", fatal.join("; "));
+        }
+        Ok(self)
+    }
+}
+
+/// The workflows and parts in the syntax of the puzzle input
+impl fmt::Display for System {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.workflows)?;
+        for part in &self.machine_parts.0 {
+            writeln!(f, "{part}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Solution for System {
+    type Answer1 = i32;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        Parser::parse(input)?.check()
+    }
+
+    fn part1(&self) -> Result<i32> {
+        let tree = self.compile(self.machine_parts.ratings());
+        Ok(self
+            .machine_parts
+            .0
+            .iter()
+            .filter(|part| tree.accepts(part))
+            .map(|part| part.rating())
+            .sum())
+    }
+
+    fn part2(&self) -> Result<usize> {
+        self.combinations(RATINGS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<()> {
+        let system = System::parse(include_str!("../input_small.txt"))?;
+        assert_eq!(system.part1()?, 19114);
+        assert_eq!(system.part2()?, 167409079868000);
+        Ok(())
+    }
+
+    #[test]
+    fn split() {
+        let decision = Decision {
+            category: Category::Musical,
+            operator: Operator::More,
+            value: 10,
+            result: RuleResult::Accepted,
+        };
+        let mut parts = PartRange::new(1..=20);
+        let matching = decision.split(&mut parts);
+        assert_eq!(matching.musical, Interval::new(11, 20));
+        assert_eq!(parts.musical, Interval::new(1, 10));
+        assert_eq!(
+            matching.combinations().unwrap() + parts.combinations().unwrap(),
+            20usize.pow(4)
+        );
+        assert!(decision.split(&mut parts).is_empty());
+    }
+
+    #[test]
+    fn extreme_thresholds() -> Result<()> {
+        for input in ["in{x>2147483647:A,R}\n", "in{x<-2147483648:A,R}\n"] {
+            let system = System::parse(input)?;
+            assert_eq!(system.part2()?, 0);
+        }
+        let system = System::parse("in{x<2147483647:R,A}\n")?;
+        assert_eq!(system.combinations(i32::MAX..=i32::MAX)?, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn too_many_combinations() -> Result<()> {
+        let system = System::parse("in{x<0:R,A}\n")?;
+        assert!(system.combinations(i32::MIN..=i32::MAX).is_err());
+        assert_eq!(PartRange::new(i32::MIN..=i32::MAX).combinations(), None);
+        assert_eq!(system.combinations(0..=9)?, 10_000);
+        Ok(())
+    }
+
+    #[test]
+    fn incomplete_part() {
+        for (input, text) in [
+            ("in{A}\n\n{x=1,m=2,a=3}\n", "{x=1,m=2,a=3}"),
+            ("in{A}\n\n{x=1,m=2,x=3,s=4}\n", "x"),
+        ] {
+            let Err(error) = System::parse(input) else {
+                panic!("each category has to be rated once");
+            };
+            let error = error.downcast::<ParseError>().unwrap();
+            assert_eq!(error.line, 3);
+            assert_eq!(error.text, text);
+        }
+    }
+}
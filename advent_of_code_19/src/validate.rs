@@ -0,0 +1,271 @@
+use crate::{PartRange, Rule, RuleResult, Workflow, Workflows};
+use std::{collections::HashSet, fmt, ops::RangeInclusive};
+
+/// Problem of the workflows, found before any part is sorted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Issue {
+    /// A rule sends parts to a workflow that does not exist
+    UndefinedLabel { workflow: String, label: String },
+    /// There is no workflow `in` to start with
+    MissingStart,
+    /// No part can ever reach the workflow
+    Unreachable(String),
+    /// Workflows sending parts to each other, the first label is repeated at the end
+    Cycle(Vec<String>),
+    /// Earlier rules of the workflow already match all parts the rule would match
+    Shadowed { workflow: String, rule: usize },
+}
+
+impl Issue {
+    /// Fatal issues make sorting parts impossible, the others only hint at mistakes
+    pub fn is_fatal(&self) -> bool {
+        matches!(
+            self,
+            Issue::UndefinedLabel { .. } | Issue::MissingStart | Issue::Cycle(_)
+        )
+    }
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Issue::UndefinedLabel { workflow, label } => {
+                write!(
+                    f,
+                    "workflow `{workflow}` sends parts to undefined `{label}`"
+                )
+            }
+            Issue::MissingStart => write!(f, "workflow `in` is missing"),
+            Issue::Unreachable(label) => write!(f, "workflow `{label}` is unreachable"),
+            Issue::Cycle(labels) => write!(f, "workflows form a cycle: {}", labels.join(" -> ")),
+            Issue::Shadowed { workflow, rule } => write!(
+                f,
+                "rule {} of workflow `{workflow}` never applies, earlier rules match all its parts",
+                rule + 1
+            ),
+        }
+    }
+}
+
+impl Workflows {
+    /// Checks the workflows for undefined labels, unreachable workflows, cycles and shadowed rules,
+    /// the latter for parts with each rating within the range
+    pub(crate) fn validate(&self, ratings: RangeInclusive<i32>) -> Vec<Issue> {
+        let mut labels = self.0.keys().map(String::as_str).collect::<Vec<_>>();
+        labels.sort();
+
+        let mut issues = Vec::new();
+        if !self.0.contains_key("in") {
+            issues.push(Issue::MissingStart);
+        }
+
+        for &label in &labels {
+            let workflow = &self.0[label];
+            for target in workflow.targets() {
+                if !self.0.contains_key(target) {
+                    issues.push(Issue::UndefinedLabel {
+                        workflow: label.to_owned(),
+                        label: target.to_owned(),
+                    });
+                }
+            }
+            for rule in workflow.shadowed_rules(ratings.clone()) {
+                issues.push(Issue::Shadowed {
+                    workflow: label.to_owned(),
+                    rule,
+                });
+            }
+        }
+
+        if self.0.contains_key("in") {
+            let reachable = self.reachable("in");
+            for &label in &labels {
+                if !reachable.contains(label) {
+                    issues.push(Issue::Unreachable(label.to_owned()));
+                }
+            }
+        }
+
+        let mut finished = HashSet::new();
+        let mut cycles = Vec::new();
+        for &label in &labels {
+            self.find_cycles(label, &mut Vec::new(), &mut finished, &mut cycles);
+        }
+        issues.extend(cycles.into_iter().map(Issue::Cycle));
+
+        issues
+    }
+
+    fn reachable<'a>(&'a self, start: &'a str) -> HashSet<&'a str> {
+        let mut reachable = HashSet::from([start]);
+        let mut queue = vec![start];
+        while let Some(label) = queue.pop() {
+            let Some(workflow) = self.0.get(label) else {
+                continue;
+            };
+            for target in workflow.targets() {
+                if reachable.insert(target) {
+                    queue.push(target);
+                }
+            }
+        }
+        reachable
+    }
+
+    /// Depth first search for workflows sending parts back to a workflow on the path
+    fn find_cycles<'a>(
+        &'a self,
+        label: &'a str,
+        path: &mut Vec<&'a str>,
+        finished: &mut HashSet<&'a str>,
+        cycles: &mut Vec<Vec<String>>,
+    ) {
+        if finished.contains(label) {
+            return;
+        }
+        if let Some(start) = path.iter().position(|&l| l == label) {
+            let cycle = path[start..]
+                .iter()
+                .chain([&label])
+                .map(|l| l.to_string())
+                .collect::<Vec<_>>();
+            if !cycles.contains(&cycle) {
+                cycles.push(cycle);
+            }
+            return;
+        }
+        let Some(workflow) = self.0.get(label) else {
+            return;
+        };
+
+        path.push(label);
+        for target in workflow.targets() {
+            self.find_cycles(target, path, finished, cycles);
+        }
+        path.pop();
+        finished.insert(label);
+    }
+}
+
+impl Workflow {
+    /// Labels of the workflows this workflow sends parts to
+    fn targets(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().filter_map(|rule| match rule.result() {
+            RuleResult::GoTo(label) => Some(label.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Indices of the rules that never apply to parts with each rating within the range
+    fn shadowed_rules(&self, ratings: RangeInclusive<i32>) -> Vec<usize> {
+        let mut parts = PartRange::new(ratings);
+        self.0
+            .iter()
+            .enumerate()
+            .filter_map(|(index, rule)| {
+                let (matching, _) = rule.apply_range(&mut parts);
+                matching.is_empty().then_some(index)
+            })
+            .collect()
+    }
+}
+
+impl Rule {
+    fn result(&self) -> &RuleResult {
+        match self {
+            Rule::Decision(decision) => &decision.result,
+            Rule::Result(result) => result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Parser, System, RATINGS};
+
+    fn issues(input: &str) -> Vec<Issue> {
+        Parser::parse(input).unwrap().workflows.validate(RATINGS)
+    }
+
+    #[test]
+    fn example_is_valid() {
+        let system = Parser::parse(include_str!("../input_small.txt")).unwrap();
+        assert_eq!(system.workflows.validate(RATINGS), []);
+    }
+
+    #[test]
+    fn undefined_and_unreachable() {
+        assert_eq!(
+            issues("in{a<5:xy,A}\nab{R}\n"),
+            [
+                Issue::UndefinedLabel {
+                    workflow: String::from("in"),
+                    label: String::from("xy")
+                },
+                Issue::Unreachable(String::from("ab")),
+            ]
+        );
+        assert_eq!(issues("ab{A}\n"), [Issue::MissingStart]);
+    }
+
+    #[test]
+    fn cycle() {
+        let issues = issues("in{x>10:ab,A}\nab{m<5:in,R}\n");
+        assert_eq!(
+            issues,
+            [Issue::Cycle(vec![
+                String::from("ab"),
+                String::from("in"),
+                String::from("ab")
+            ])]
+        );
+        assert!(issues[0].is_fatal());
+    }
+
+    #[test]
+    fn shadowed() {
+        assert_eq!(
+            issues("in{a<10:A,a<5:R,s>0:R,A}\n"),
+            [
+                Issue::Shadowed {
+                    workflow: String::from("in"),
+                    rule: 1
+                },
+                Issue::Shadowed {
+                    workflow: String::from("in"),
+                    rule: 3
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn shadowed_within_ratings() {
+        // `s>0` matches all parts only if no rating is below 1
+        let system = System::parse_unchecked("in{s>0:R,A}\n").unwrap();
+        let shadowed = Issue::Shadowed {
+            workflow: String::from("in"),
+            rule: 1,
+        };
+        assert_eq!(system.issues(RATINGS), [shadowed]);
+        assert_eq!(system.issues(-5..=5), []);
+    }
+
+    #[test]
+    fn all_issues_reported() {
+        let system = System::parse_unchecked("ab{x<5:cd,cd}\ncd{ab}\nef{x<5:R,x<3:A,R}\n").unwrap();
+        let issues = system.issues(RATINGS);
+        assert!(issues.contains(&Issue::MissingStart));
+        assert!(issues.contains(&Issue::Cycle(vec![
+            String::from("ab"),
+            String::from("cd"),
+            String::from("ab")
+        ])));
+        assert!(issues.contains(&Issue::Shadowed {
+            workflow: String::from("ef"),
+            rule: 1
+        }));
+        assert!(issues.iter().any(Issue::is_fatal));
+    }
+}
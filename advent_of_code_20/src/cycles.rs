@@ -0,0 +1,197 @@
+use crate::{Kind, Modules, State};
+use anyhow::{anyhow, bail, Result};
+use std::fmt;
+
+/// Module that has to receive a low pulse
+const TARGET: &str = "rx";
+/// Number of times each input has to send a high pulse to prove its cycle
+const CONFIRMATIONS: usize = 3;
+/// Presses after which the search for the cycles gives up
+const MAX_PRESSES: usize = 1_000_000;
+
+/// Cycles of the inputs of the conjunction feeding `rx`.
+///
+/// The conjunction sends a low pulse to `rx` once all its inputs send a high pulse during the
+/// same press. Each input sends a high pulse every n-th press, so this happens first after the
+/// least common multiple of their cycles.
+pub struct Cycles {
+    conjunction: String,
+    /// Inputs of the conjunction with the presses between their high pulses
+    inputs: Vec<(String, usize)>,
+}
+
+impl Cycles {
+    /// Presses until `rx` receives a low pulse
+    pub fn presses(&self) -> Result<usize> {
+        self.inputs
+            .iter()
+            .try_fold(1, |presses, &(_, cycle)| lcm(presses, cycle))
+            .ok_or_else(too_many_presses)
+    }
+
+    /// Presses between the high pulses of each input of the conjunction
+    pub fn inputs(&self) -> &[(String, usize)] {
+        &self.inputs
+    }
+}
+
+impl fmt::Display for Cycles {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{TARGET} is fed by &{}", self.conjunction)?;
+        for (input, cycle) in &self.inputs {
+            writeln!(f, "  {input} sends a high pulse every {cycle} presses")?;
+        }
+        match self.presses() {
+            Ok(presses) => write!(f, "{TARGET} receives a low pulse after {presses} presses"),
+            Err(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl Modules {
+    /// Finds the cycles of the inputs of the conjunction feeding `rx` by pushing the button
+    /// after a reset
+    pub fn cycles(&mut self) -> Result<Cycles> {
+        self.reset();
+
+        let target = self.id(TARGET);
+        let feeding = (0..self.modules.len())
+            .filter(|&id| {
+                self.modules[id]
+                    .destinations
+                    .iter()
+                    .any(|cable| Some(cable.to) == target)
+            })
+            .collect::<Vec<_>>();
+        let id = match feeding.as_slice() {
+            [id] if self.modules[*id].kind == Kind::Conjunction => *id,
+            [_] => bail!("{TARGET} is not fed by a conjunction"),
+            [] => bail!("no module sends pulses to {TARGET}"),
+            _ => bail!("{TARGET} is fed by more than one module"),
+        };
+        let mut inputs = self.inputs(id).collect::<Vec<_>>();
+        inputs.sort_by_key(|&(input, _)| self.name(input));
+
+        // presses at which each input sent a high pulse to the conjunction
+        let mut high_pulses = vec![Vec::new(); inputs.len()];
+        for press in 1..=MAX_PRESSES {
+            self.push_button_with(|pulse| {
+                if pulse.to == id && pulse.state == State::High {
+                    let input = inputs
+                        .iter()
+                        .position(|&(_, bit)| bit == pulse.input)
+                        .unwrap();
+                    if high_pulses[input].last() != Some(&press) {
+                        high_pulses[input].push(press);
+                    }
+                }
+            });
+
+            if high_pulses
+                .iter()
+                .all(|presses| presses.len() >= CONFIRMATIONS)
+            {
+                let mut cycles = Vec::new();
+                for ((input, _), presses) in inputs.into_iter().zip(high_pulses) {
+                    let input = self.name(input).to_owned();
+                    // the cycle has to start with the first press to repeat at its multiples
+                    let cycle = presses[0];
+                    if presses
+                        .iter()
+                        .zip(1..)
+                        .any(|(&press, n)| press != n * cycle)
+                    {
+                        bail!("{input} sends high pulses at irregular presses {presses:?}");
+                    }
+                    cycles.push((input, cycle));
+                }
+                return Ok(Cycles {
+                    conjunction: self.name(id).to_owned(),
+                    inputs: cycles,
+                });
+            }
+        }
+        bail!("no cycles found within {MAX_PRESSES} presses")
+    }
+}
+
+fn too_many_presses() -> anyhow::Error {
+    anyhow!(
+        "{TARGET} receives a low pulse after more than {} presses",
+        usize::MAX
+    )
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn lcm(a: usize, b: usize) -> Option<usize> {
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+
+    /// Two flip-flops counting the presses, inverted to send a high pulse every 2nd and 4th press
+    const COUNTER: &str = "broadcaster -> a\n\
+                           %a -> b, ia\n\
+                           %b -> ib\n\
+                           &ia -> hub\n\
+                           &ib -> hub\n\
+                           &hub -> rx\n";
+
+    #[test]
+    fn counter() -> Result<()> {
+        let mut modules = Modules::parse(COUNTER)?;
+        let cycles = modules.cycles()?;
+        assert_eq!(
+            cycles.inputs(),
+            [(String::from("ia"), 2), (String::from("ib"), 4)]
+        );
+        assert_eq!(cycles.presses()?, 4);
+
+        // pushing the button until rx receives a low pulse agrees
+        modules.reset();
+        let mut presses = 0;
+        let rx = modules.id(TARGET).unwrap();
+        let mut received = false;
+        while !received {
+            presses += 1;
+            modules.push_button_with(|pulse| {
+                received |= pulse.to == rx && pulse.state == State::Low;
+            });
+        }
+        assert_eq!(presses, 4);
+        Ok(())
+    }
+
+    #[test]
+    fn missing_target() -> Result<()> {
+        let mut modules = Modules::parse(include_str!("../input_small.txt"))?;
+        assert!(modules.cycles().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn too_many_presses() {
+        let cycles = Cycles {
+            conjunction: String::from("hub"),
+            inputs: vec![
+                (String::from("ia"), 4_294_967_291),
+                (String::from("ib"), 4_294_967_279),
+                (String::from("ic"), 4_294_967_231),
+            ],
+        };
+        assert!(cycles.presses().is_err());
+        assert!(cycles
+            .to_string()
+            .ends_with("more than 18446744073709551615 presses"));
+    }
+}
@@ -0,0 +1,132 @@
+use crate::{Kind, Modules, BROADCAST};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write,
+};
+
+impl Modules {
+    /// Renders the wiring of the modules in the Graphviz DOT language.
+    ///
+    /// Modules that only receive pulses, like `rx`, are highlighted as sinks. With `cluster` the
+    /// sub-networks that are only connected by the broadcaster and the modules feeding the sinks
+    /// are drawn in boxes of their own.
+    pub fn to_dot(&self, cluster: bool) -> String {
+        let mut ids = (0..self.modules.len()).collect::<Vec<_>>();
+        ids.sort_by_key(|&id| self.name(id));
+
+        let mut dot = String::from("digraph modules {\n    node [fontname=monospace];\n");
+        let mut clustered = BTreeSet::new();
+        if cluster {
+            for (index, sub_network) in self.sub_networks().into_iter().enumerate() {
+                writeln!(dot, "    subgraph cluster_{index} {{").unwrap();
+                writeln!(dot, "        style=rounded;").unwrap();
+                for name in &sub_network {
+                    writeln!(dot, "        {}", self.node(self.ids[*name])).unwrap();
+                }
+                dot.push_str("    }\n");
+                clustered.extend(sub_network);
+            }
+        }
+        for &id in ids.iter().filter(|&&id| !clustered.contains(self.name(id))) {
+            writeln!(dot, "    {}", self.node(id)).unwrap();
+        }
+
+        for &id in &ids {
+            for cable in &self.modules[id].destinations {
+                let (from, to) = (self.name(id), self.name(cable.to));
+                writeln!(dot, "    \"{from}\" -> \"{to}\";").unwrap();
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Groups of modules connected with each other, apart from the broadcaster, the sinks and the
+    /// modules feeding the sinks
+    fn sub_networks(&self) -> Vec<BTreeSet<&str>> {
+        let is_sink = |id: usize| self.modules[id].kind == Kind::Sink;
+        let excluded = |id: usize| {
+            id == BROADCAST
+                || is_sink(id)
+                || self.modules[id]
+                    .destinations
+                    .iter()
+                    .any(|cable| is_sink(cable.to))
+        };
+
+        // cables in both directions between the remaining modules
+        let mut neighbours = BTreeMap::<&str, Vec<&str>>::new();
+        for id in (0..self.modules.len()).filter(|&id| !excluded(id)) {
+            let name = self.name(id);
+            neighbours.entry(name).or_default();
+            for cable in self.modules[id]
+                .destinations
+                .iter()
+                .filter(|cable| !excluded(cable.to))
+            {
+                let destination = self.name(cable.to);
+                neighbours.entry(name).or_default().push(destination);
+                neighbours.entry(destination).or_default().push(name);
+            }
+        }
+        let mut visited = BTreeSet::new();
+        let mut sub_networks = Vec::new();
+        for &start in neighbours.keys() {
+            if !visited.insert(start) {
+                continue;
+            }
+            let mut sub_network = BTreeSet::from([start]);
+            let mut queue = vec![start];
+            while let Some(id) = queue.pop() {
+                for &next in &neighbours[id] {
+                    if visited.insert(next) {
+                        sub_network.insert(next);
+                        queue.push(next);
+                    }
+                }
+            }
+            if sub_network.len() > 1 {
+                sub_networks.push(sub_network);
+            }
+        }
+        sub_networks
+    }
+
+    fn node(&self, id: usize) -> String {
+        let name = self.name(id);
+        let style = match self.modules[id].kind {
+            Kind::FlipFlop => format!("label=\"%{name}\", shape=box"),
+            Kind::Conjunction => format!(
+                "label=\"&{name}\", shape=invtrapezium, style=filled, fillcolor=lightyellow"
+            ),
+            Kind::Sink => {
+                format!("label=\"{name}\", shape=doubleoctagon, style=filled, fillcolor=gold")
+            }
+            Kind::Broadcaster => {
+                format!("label=\"{name}\", shape=doublecircle, style=filled, fillcolor=lightblue")
+            }
+        };
+        format!("\"{name}\" [{style}];")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use common::Solution;
+
+    #[test]
+    fn example() -> Result<()> {
+        let modules = Modules::parse(include_str!("../input_small_2.txt"))?;
+        let dot = modules.to_dot(false);
+        assert!(dot.contains("\"a\" [label=\"%a\", shape=box];"));
+        assert!(dot.contains("\"output\" [label=\"output\", shape=doubleoctagon"));
+        assert!(dot.contains("\"broadcaster\" -> \"a\";"));
+        assert!(!dot.contains("subgraph"));
+
+        assert_eq!(modules.sub_networks(), [BTreeSet::from(["a", "b", "inv"])]);
+        assert!(modules.to_dot(true).contains("subgraph cluster_0 {"));
+        Ok(())
+    }
+}
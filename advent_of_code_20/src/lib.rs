@@ -0,0 +1,349 @@
+mod cycles;
+mod dot;
+mod trace;
+
+pub use cycles::Cycles;
+pub use trace::{Trace, TracedPulse};
+
+use anyhow::Result;
+use common::{
+    parse::{self, ParseError},
+    Solution,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+
+const BROADCASTER: &str = "broadcaster";
+/// Id of the broadcaster, the button sends its pulse to it
+const BROADCAST: usize = 0;
+/// Button presses to warm up the modules in part 1
+const PRESSES: usize = 1000;
+/// Inputs a conjunction can remember, one bit each
+const MAX_INPUTS: u32 = u64::BITS;
+
+struct Parser;
+
+impl Parser {
+    fn parse(input: &str) -> Result<Modules, ParseError> {
+        let mut declared = Vec::new();
+        for line in input.lines() {
+            let (source, destination) = parse::split_once(input, line, " -> ")?;
+            let (kind, name) = match source.get(0..1) {
+                Some("%") => (Kind::FlipFlop, &source[1..]),
+                Some("&") => (Kind::Conjunction, &source[1..]),
+                _ if source == BROADCASTER => (Kind::Broadcaster, source),
+                _ => {
+                    let message = "expected `%<name>`, `&<name>` or `broadcaster`";
+                    return Err(ParseError::at(input, source, message));
+                }
+            };
+            if declared.iter().any(|&(_, other, _)| other == name) {
+                return Err(ParseError::at(input, source, "module is declared twice"));
+            }
+            let destinations = destination.split(',').map(str::trim).collect::<Vec<_>>();
+            declared.push((kind, name, destinations));
+        }
+
+        // the broadcaster gets its id first, destinations that are not declared become sinks
+        let mut modules = Modules::new();
+        modules.intern(BROADCASTER, Kind::Broadcaster);
+        for &(kind, name, _) in declared
+            .iter()
+            .filter(|&&(kind, ..)| kind != Kind::Broadcaster)
+        {
+            modules.intern(name, kind);
+        }
+        for (_, name, destinations) in &declared {
+            let from = modules.ids[*name];
+            for &destination in destinations {
+                let to = match modules.ids.get(destination) {
+                    Some(&to) => to,
+                    None => modules.intern(destination, Kind::Sink),
+                };
+                // only conjunctions remember their inputs
+                let inputs = modules.modules[to].inputs;
+                let input = match modules.modules[to].kind {
+                    Kind::Conjunction if inputs.count_ones() == MAX_INPUTS => {
+                        let message = format!("a conjunction can have at most {MAX_INPUTS} inputs");
+                        return Err(ParseError::at(input, destination, message));
+                    }
+                    Kind::Conjunction => 1 << inputs.count_ones(),
+                    _ => 0,
+                };
+                modules.modules[to].inputs |= input;
+                modules.modules[from].destinations.push(Cable { to, input });
+            }
+        }
+        modules.flip_flops = vec![0; modules.modules.len().div_ceil(64)];
+        modules.memory = vec![0; modules.modules.len()];
+        Ok(modules)
+    }
+}
+
+/// Modules of the machine, connected by their cables.
+///
+/// Each module is known by its index, the state of all flip-flops is kept in a bitset and each
+/// conjunction remembers the last pulses of its inputs as one bit per input.
+#[derive(Clone)]
+pub struct Modules {
+    names: Vec<String>,
+    ids: HashMap<String, usize>,
+    modules: Vec<Module>,
+    /// Bit per module, set for flip-flops that are on
+    flip_flops: Vec<u64>,
+    /// Inputs that sent a high pulse last, per conjunction
+    memory: Vec<u64>,
+    low_pulses: usize,
+    high_pulses: usize,
+    /// Button presses since the last reset
+    presses: usize,
+}
+
+impl Modules {
+    fn new() -> Self {
+        Self {
+            names: Vec::new(),
+            ids: HashMap::new(),
+            modules: Vec::new(),
+            flip_flops: Vec::new(),
+            memory: Vec::new(),
+            low_pulses: 0,
+            high_pulses: 0,
+            presses: 0,
+        }
+    }
+
+    /// Adds a module without cables, returns its id
+    fn intern(&mut self, name: &str, kind: Kind) -> usize {
+        let id = self.modules.len();
+        self.names.push(String::from(name));
+        self.ids.insert(String::from(name), id);
+        self.modules.push(Module {
+            kind,
+            destinations: Vec::new(),
+            inputs: 0,
+        });
+        id
+    }
+
+    fn id(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    fn name(&self, id: usize) -> &str {
+        &self.names[id]
+    }
+
+    /// The modules sending pulses to the module, with the bit of their input
+    fn inputs(&self, id: usize) -> impl Iterator<Item = (usize, u64)> + '_ {
+        self.modules
+            .iter()
+            .enumerate()
+            .flat_map(move |(from, module)| {
+                module
+                    .destinations
+                    .iter()
+                    .filter(move |cable| cable.to == id)
+                    .map(move |cable| (from, cable.input))
+            })
+    }
+
+    fn is_on(&self, id: usize) -> bool {
+        self.flip_flops[id / 64] & (1 << (id % 64)) != 0
+    }
+
+    /// Restores the initial state of all modules and forgets the pulses sent so far
+    pub fn reset(&mut self) {
+        self.flip_flops.fill(0);
+        self.memory.fill(0);
+        self.low_pulses = 0;
+        self.high_pulses = 0;
+        self.presses = 0;
+    }
+
+    /// Product of the low and high pulses sent while pushing the button 1000 times after a reset
+    pub fn warm_up(&mut self) -> usize {
+        self.reset();
+        for _ in 0..PRESSES {
+            self.push_button();
+        }
+        self.high_pulses * self.low_pulses
+    }
+
+    fn push_button(&mut self) {
+        self.push_button_with(|_| {});
+    }
+
+    /// Pushes the button, passing each pulse to `observe` before it is received
+    fn push_button_with(&mut self, mut observe: impl FnMut(&Pulse)) {
+        self.presses += 1;
+        let mut pulses = VecDeque::new();
+        pulses.push_back(Pulse::BUTTON);
+
+        while let Some(pulse) = pulses.pop_front() {
+            observe(&pulse);
+            self.receive(&pulse, &mut pulses);
+        }
+    }
+
+    /// Passes the pulse to its module, which queues the pulses it sends in turn
+    fn receive(&mut self, pulse: &Pulse, pulses: &mut VecDeque<Pulse>) {
+        match pulse.state {
+            State::High => self.high_pulses += 1,
+            State::Low => self.low_pulses += 1,
+        }
+
+        let module = &self.modules[pulse.to];
+        let state = match module.kind {
+            Kind::Broadcaster => State::Low,
+            Kind::FlipFlop if pulse.state == State::Low => {
+                let bits = &mut self.flip_flops[pulse.to / 64];
+                *bits ^= 1 << (pulse.to % 64);
+                State::from(*bits & (1 << (pulse.to % 64)) != 0)
+            }
+            Kind::FlipFlop | Kind::Sink => return,
+            Kind::Conjunction => {
+                let memory = &mut self.memory[pulse.to];
+                match pulse.state {
+                    State::High => *memory |= pulse.input,
+                    State::Low => *memory &= !pulse.input,
+                }
+                State::from(*memory != module.inputs)
+            }
+        };
+
+        pulses.extend(module.destinations.iter().map(|cable| Pulse {
+            state,
+            from: pulse.to,
+            to: cable.to,
+            input: cable.input,
+        }));
+    }
+}
+
+#[derive(Clone)]
+struct Module {
+    kind: Kind,
+    destinations: Vec<Cable>,
+    /// Bits of all inputs of a conjunction, it sends a low pulse once all of them are set
+    inputs: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Broadcaster,
+    FlipFlop,
+    Conjunction,
+    /// Module that only receives pulses, like `rx`, it is not declared in the input
+    Sink,
+}
+
+#[derive(Clone, Copy)]
+struct Cable {
+    to: usize,
+    /// Bit of the sending module among the inputs of the receiving conjunction, 0 for others
+    input: u64,
+}
+
+#[derive(Clone)]
+struct Pulse {
+    state: State,
+    from: usize,
+    to: usize,
+    /// Bit of the sending module among the inputs of the receiving conjunction, 0 for others
+    input: u64,
+}
+
+impl Pulse {
+    const BUTTON: Self = Self {
+        state: State::Low,
+        from: BROADCAST,
+        to: BROADCAST,
+        input: 0,
+    };
+}
+
+/// Level of a pulse
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum State {
+    High,
+    Low,
+}
+
+impl From<bool> for State {
+    fn from(high: bool) -> Self {
+        if high {
+            Self::High
+        } else {
+            Self::Low
+        }
+    }
+}
+
+impl Solution for Modules {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Parser::parse(input)?)
+    }
+
+    fn part1(&self) -> Result<usize> {
+        Ok(self.clone().warm_up())
+    }
+
+    fn part2(&self) -> Result<usize> {
+        self.clone().cycles()?.presses()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_1() -> Result<()> {
+        let modules = Modules::parse(include_str!("../input_small.txt"))?;
+        assert_eq!(modules.part1()?, 32000000);
+        Ok(())
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        let modules = Modules::parse(include_str!("../input_small_2.txt"))?;
+        assert_eq!(modules.part1()?, 11687500);
+        Ok(())
+    }
+
+    #[test]
+    fn reset() -> Result<()> {
+        let mut modules = Modules::parse(include_str!("../input_small_2.txt"))?;
+        assert_eq!(modules.warm_up(), 11687500);
+        modules.push_button();
+        assert_eq!(modules.warm_up(), 11687500);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(Modules::parse("broadcaster -> a\n%a -> b\n&a -> b\n").is_err());
+
+        let inputs = (0..=MAX_INPUTS)
+            .map(|i| format!("%f{i} -> con\n"))
+            .collect::<String>();
+        assert!(Modules::parse(&format!("broadcaster -> f0\n{inputs}&con -> rx\n")).is_err());
+    }
+
+    #[test]
+    fn many_inputs() -> Result<()> {
+        // sinks do not remember their inputs, so they can have any number of them
+        let inputs = (0..=MAX_INPUTS)
+            .map(|i| format!("%f{i} -> out\n"))
+            .collect::<String>();
+        let mut modules = Modules::parse(&format!("broadcaster -> f0\n{inputs}"))?;
+        // f0 sends a high pulse every other press
+        assert_eq!(modules.warm_up(), 2500 * 500);
+        Ok(())
+    }
+}
@@ -0,0 +1,280 @@
+use crate::{Kind, Modules, Pulse, State};
+use anyhow::{anyhow, bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, VecDeque},
+    io::{self, BufRead, Write},
+};
+
+/// Name of the button in a trace, it sends the first pulse of each press
+const BUTTON: &str = "button";
+
+/// Pulse sent while pushing the button
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TracedPulse {
+    /// Button press since the last reset, starting at 1
+    pub press: usize,
+    /// Index of the pulse within the press
+    pub seq: usize,
+    pub from: String,
+    pub to: String,
+    pub state: State,
+}
+
+impl TracedPulse {
+    fn new(press: usize, seq: usize, pulse: &Pulse, modules: &Modules) -> Self {
+        Self {
+            press,
+            seq,
+            // the broadcaster receives the first pulse from the button
+            from: match seq {
+                0 => BUTTON.to_owned(),
+                _ => modules.name(pulse.from).to_owned(),
+            },
+            to: modules.name(pulse.to).to_owned(),
+            state: pulse.state,
+        }
+    }
+
+    /// Whether the pulse was sent or received by the module
+    pub fn involves(&self, module: &str) -> bool {
+        self.from == module || self.to == module
+    }
+}
+
+/// Pulses of consecutive button presses, in the order they were sent
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Trace(Vec<TracedPulse>);
+
+impl Trace {
+    pub fn pulses(&self) -> &[TracedPulse] {
+        &self.0
+    }
+
+    /// The pulses sent or received by the module
+    pub fn filter(&self, module: &str) -> Trace {
+        Trace(
+            self.0
+                .iter()
+                .filter(|pulse| pulse.involves(module))
+                .cloned()
+                .collect(),
+        )
+    }
+
+    /// Writes a JSON object per pulse and line
+    pub fn write_json_lines(&self, mut writer: impl Write) -> io::Result<()> {
+        for pulse in &self.0 {
+            serde_json::to_writer(&mut writer, pulse)?;
+            writeln!(writer)?;
+        }
+        Ok(())
+    }
+
+    /// Fails unless the trace holds all pulses of consecutive presses starting at the first one
+    fn check_complete(&self) -> Result<()> {
+        let mut previous: Option<&TracedPulse> = None;
+        for pulse in &self.0 {
+            let (press, seq) = match previous {
+                Some(previous) if previous.press == pulse.press => (pulse.press, previous.seq + 1),
+                Some(previous) => (previous.press + 1, 0),
+                None => (1, 0),
+            };
+            if pulse.press != press || pulse.seq != seq || (seq == 0) != (pulse.from == BUTTON) {
+                bail!(
+                    "the trace is not complete, expected pulse {seq} of press {press} but found \
+                     pulse {} of press {} from {}",
+                    pulse.seq,
+                    pulse.press,
+                    pulse.from
+                );
+            }
+            previous = Some(pulse);
+        }
+        Ok(())
+    }
+
+    pub fn read_json_lines(reader: impl BufRead) -> Result<Self> {
+        let mut pulses = Vec::new();
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let pulse = serde_json::from_str(&line)
+                .with_context(|| format!("invalid pulse on line {}", index + 1))?;
+            pulses.push(pulse);
+        }
+        Ok(Self(pulses))
+    }
+}
+
+impl Modules {
+    /// Pushes the button the given number of times, recording all pulses
+    pub fn trace(&mut self, presses: usize) -> Trace {
+        let mut pulses = Vec::new();
+        for _ in 0..presses {
+            let press = self.presses + 1;
+            let mut recorded = Vec::new();
+            self.push_button_with(|pulse| recorded.push(pulse.clone()));
+            pulses.extend(
+                recorded
+                    .iter()
+                    .enumerate()
+                    .map(|(seq, pulse)| TracedPulse::new(press, seq, pulse, self)),
+            );
+        }
+        Trace(pulses)
+    }
+
+    /// The pulse sent along the cable between the traced modules
+    fn pulse(&self, traced: &TracedPulse) -> Result<Pulse> {
+        if traced.from == BUTTON {
+            return Ok(Pulse::BUTTON);
+        }
+        let id = |name: &str| {
+            self.id(name)
+                .ok_or_else(|| anyhow!("unknown module {name}"))
+        };
+        let (from, to) = (id(&traced.from)?, id(&traced.to)?);
+        let Some(cable) = self.modules[from]
+            .destinations
+            .iter()
+            .find(|cable| cable.to == to)
+        else {
+            bail!("{} is not connected to {}", traced.from, traced.to);
+        };
+        Ok(Pulse {
+            state: traced.state,
+            from,
+            to,
+            input: cable.input,
+        })
+    }
+
+    /// Restores the state of the modules after the press by replaying the pulses of an unfiltered
+    /// trace, which has to start after a reset
+    pub fn replay(&mut self, trace: &Trace, press: usize) -> Result<()> {
+        trace.check_complete()?;
+        let recorded = trace.0.last().map_or(0, |pulse| pulse.press);
+        if press > recorded {
+            bail!("the trace ends after press {recorded}, it cannot be replayed until {press}");
+        }
+
+        self.reset();
+        let mut ignored = VecDeque::new();
+        for traced in trace.0.iter().take_while(|pulse| pulse.press <= press) {
+            let pulse = self.pulse(traced)?;
+            self.presses = traced.press;
+            self.receive(&pulse, &mut ignored);
+            ignored.clear();
+        }
+        Ok(())
+    }
+
+    /// State of the flip-flops, `on` or `off`, and the last pulses the conjunctions received
+    pub fn states(&self) -> BTreeMap<String, String> {
+        (0..self.modules.len())
+            .filter_map(|id| {
+                let state = match self.modules[id].kind {
+                    Kind::Broadcaster | Kind::Sink => return None,
+                    Kind::FlipFlop if self.is_on(id) => String::from("on"),
+                    Kind::FlipFlop => String::from("off"),
+                    Kind::Conjunction => self
+                        .inputs(id)
+                        .map(|(input, bit)| {
+                            let state = State::from(self.memory[id] & bit != 0);
+                            (self.name(input), state)
+                        })
+                        .collect::<BTreeMap<_, _>>()
+                        .into_iter()
+                        .map(|(input, state)| match state {
+                            State::High => format!("{input}=high"),
+                            State::Low => format!("{input}=low"),
+                        })
+                        .collect::<Vec<_>>()
+                        .join(","),
+                };
+                Some((self.name(id).to_owned(), state))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+
+    #[test]
+    fn record() -> Result<()> {
+        let mut modules = Modules::parse(include_str!("../input_small_2.txt"))?;
+        let trace = modules.trace(4);
+
+        let first = &trace.pulses()[..3];
+        assert_eq!(
+            first
+                .iter()
+                .map(|pulse| (
+                    pulse.press,
+                    pulse.seq,
+                    pulse.from.as_str(),
+                    pulse.to.as_str()
+                ))
+                .collect::<Vec<_>>(),
+            [
+                (1, 0, "button", "broadcaster"),
+                (1, 1, "broadcaster", "a"),
+                (1, 2, "a", "inv")
+            ]
+        );
+        assert!(trace
+            .filter("con")
+            .pulses()
+            .iter()
+            .all(|pulse| pulse.involves("con")));
+
+        let mut json = Vec::new();
+        trace.write_json_lines(&mut json)?;
+        assert_eq!(Trace::read_json_lines(json.as_slice())?, trace);
+        Ok(())
+    }
+
+    #[test]
+    fn replay() -> Result<()> {
+        let mut modules = Modules::parse(include_str!("../input_small_2.txt"))?;
+        let trace = modules.trace(4);
+        let after_four = modules.states();
+
+        let mut replayed = Modules::parse(include_str!("../input_small_2.txt"))?;
+        replayed.replay(&trace, 4)?;
+        assert_eq!(replayed.states(), after_four);
+
+        modules.reset();
+        modules.trace(2);
+        replayed.replay(&trace, 2)?;
+        assert_eq!(replayed.states(), modules.states());
+        Ok(())
+    }
+
+    #[test]
+    fn replay_incomplete() -> Result<()> {
+        let mut modules = Modules::parse(include_str!("../input_small_2.txt"))?;
+        let trace = modules.trace(4);
+
+        let error = modules.replay(&trace, 5).unwrap_err();
+        assert!(error.to_string().contains("ends after press 4"));
+
+        let error = modules.replay(&trace.filter("con"), 2).unwrap_err();
+        assert!(error.to_string().contains("not complete"));
+
+        let mut gap = trace.clone();
+        gap.0.remove(3);
+        assert!(modules.replay(&gap, 1).is_err());
+
+        let mut second = trace.clone();
+        second.0.retain(|pulse| pulse.press > 1);
+        assert!(modules.replay(&second, 2).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,160 @@
+use anyhow::Result;
+use common::{geometry::Position, grid::Grid, parse::ParseError, Solution, Unsolved};
+use std::fmt;
+
+/// Steps the elf can walk with the real input
+const STEPS: usize = 64;
+/// Steps the elf can walk in the example of the puzzle
+const EXAMPLE_STEPS: usize = 6;
+
+struct Parser;
+
+impl Parser {
+    fn parse(input: &str) -> Result<Map, ParseError> {
+        let grid = Grid::parse(input)?;
+        if !grid.iter().any(|(_, &field)| field == Field::Start) {
+            return Err(ParseError::after(
+                input,
+                input,
+                "expected a starting position `S`",
+            ));
+        }
+        Ok(Map(grid))
+    }
+}
+
+#[derive(Clone)]
+struct Map(Grid<Field>);
+
+impl Map {
+    fn count_reachable_tiles(mut self, steps: usize) -> usize {
+        let start = self.find_starting_pos();
+        self.go_to(start, 0, steps);
+
+        self.find_even_fields()
+    }
+
+    fn find_starting_pos(&self) -> Position {
+        self.0
+            .iter()
+            .find(|(_, &field)| field == Field::Start)
+            .map(|(pos, _)| pos)
+            .expect("starting pos not found")
+    }
+
+    fn find_even_fields(&self) -> usize {
+        self.0
+            .iter()
+            .filter(|(_, field)| matches!(field, Field::Even(_)))
+            .count()
+    }
+
+    fn go_to(&mut self, to: Position, step: usize, max_steps: usize) {
+        if !self.0[to].can_visit(step) || step > max_steps {
+            return;
+        }
+
+        self.0[to] = Field::from(step);
+
+        let neighbors = self.0.neighbours(to).collect::<Vec<_>>();
+        for neighbor in neighbors {
+            self.go_to(neighbor, step + 1, max_steps);
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Garden,
+    Rock,
+    Start,
+    Odd(usize),
+    Even(usize),
+}
+
+impl Field {
+    fn can_visit(&self, steps: usize) -> bool {
+        match *self {
+            Field::Even(even) => steps < even,
+            Field::Odd(odd) => steps < odd,
+            Field::Garden | Field::Start => true,
+            Field::Rock => false,
+        }
+    }
+}
+
+impl TryFrom<char> for Field {
+    type Error = &'static str;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Field::Garden),
+            '#' => Ok(Field::Rock),
+            'S' => Ok(Field::Start),
+            _ => Err("invalid field"),
+        }
+    }
+}
+
+impl From<usize> for Field {
+    fn from(step: usize) -> Self {
+        if step & 1 == 0 {
+            Field::Even(step)
+        } else {
+            Field::Odd(step)
+        }
+    }
+}
+
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Garden => write!(f, "."),
+            Self::Rock => write!(f, "#"),
+            Self::Start => write!(f, "S"),
+            Self::Odd(_) => write!(f, "-"),
+            Self::Even(_) => write!(f, "O"),
+        }
+    }
+}
+
+/// Garden of the elf and the steps the elf can walk in it
+pub struct Garden {
+    map: Map,
+    steps: usize,
+}
+
+impl Solution for Garden {
+    type Answer1 = usize;
+    type Answer2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            map: Parser::parse(input)?,
+            steps: STEPS,
+        })
+    }
+
+    fn parse_example(input: &str) -> Result<Self> {
+        Ok(Self {
+            map: Parser::parse(input)?,
+            steps: EXAMPLE_STEPS,
+        })
+    }
+
+    fn part1(&self) -> Result<usize> {
+        Ok(self.map.clone().count_reachable_tiles(self.steps))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<()> {
+        let garden = Garden::parse_example(include_str!("../input_small.txt"))?;
+        assert_eq!(garden.part1()?, 16);
+        Ok(())
+    }
+}
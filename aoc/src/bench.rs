@@ -0,0 +1,116 @@
+use crate::{get_parser, input::Input, ledger::Ledger, parse_input, solve};
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::{
+    fs,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+/// Timings of one phase of a day over all runs
+#[derive(Serialize)]
+pub struct Measurement {
+    day: u8,
+    /// `parse`, `part1` or `part2`
+    phase: String,
+    runs: usize,
+    min_ns: u128,
+    mean_ns: u128,
+    max_ns: u128,
+}
+
+impl Measurement {
+    fn new(day: u8, phase: &str, timings: &[Duration]) -> Self {
+        let total = timings.iter().sum::<Duration>();
+        Self {
+            day,
+            phase: phase.to_owned(),
+            runs: timings.len(),
+            min_ns: timings.iter().min().unwrap().as_nanos(),
+            mean_ns: total.as_nanos() / timings.len() as u128,
+            max_ns: timings.iter().max().unwrap().as_nanos(),
+        }
+    }
+}
+
+fn measure<T>(timings: &mut Vec<Duration>, task: impl FnOnce() -> T) -> T {
+    let start = Instant::now();
+    let result = task();
+    timings.push(start.elapsed());
+    result
+}
+
+/// Times parsing and solving the puzzle input of a day, parts skipped by the ledger are left out
+fn bench_day(day: u8, runs: usize, ledger: &Ledger) -> Result<Vec<Measurement>> {
+    let Some(parse) = get_parser(day) else {
+        return Ok(Vec::new());
+    };
+    let input = Input::Puzzle.read(day)?;
+
+    let mut parse_timings = Vec::new();
+    let mut part_timings = [Vec::new(), Vec::new()];
+    let mut solved = [true, true];
+    for _ in 0..runs {
+        let puzzle = measure(&mut parse_timings, || {
+            parse_input(parse, &input, &Input::Puzzle, day)
+        })?;
+
+        for part in 1..=2 {
+            let index = part as usize - 1;
+            if !solved[index] || ledger.is_skipped(day, part) {
+                continue;
+            }
+
+            let answer = measure(&mut part_timings[index], || solve(puzzle.as_ref(), part))?;
+            solved[index] = answer.is_some();
+        }
+    }
+
+    let mut measurements = vec![Measurement::new(day, "parse", &parse_timings)];
+    for (index, timings) in part_timings.iter().enumerate() {
+        if solved[index] && !timings.is_empty() {
+            let phase = format!("part{}", index + 1);
+            measurements.push(Measurement::new(day, &phase, timings));
+        }
+    }
+    Ok(measurements)
+}
+
+fn format_duration(nanos: u128) -> String {
+    match nanos {
+        0..=9_999 => format!("{nanos} ns"),
+        10_000..=9_999_999 => format!("{} µs", nanos / 1_000),
+        10_000_000..=9_999_999_999 => format!("{} ms", nanos / 1_000_000),
+        _ => format!("{} s", nanos / 1_000_000_000),
+    }
+}
+
+pub fn bench(day: Option<u8>, runs: usize, json: Option<&Path>) -> Result<()> {
+    let ledger = Ledger::load()?;
+
+    println!(
+        "{:>3}  {:<5}  {:>4}  {:>9}  {:>9}  {:>9}",
+        "day", "phase", "runs", "min", "mean", "max"
+    );
+    let mut measurements = Vec::new();
+    for day in day.map_or_else(|| (1..=25).collect(), |day| vec![day]) {
+        for measurement in bench_day(day, runs, &ledger)? {
+            println!(
+                "{:>3}  {:<5}  {:>4}  {:>9}  {:>9}  {:>9}",
+                measurement.day,
+                measurement.phase,
+                measurement.runs,
+                format_duration(measurement.min_ns),
+                format_duration(measurement.mean_ns),
+                format_duration(measurement.max_ns),
+            );
+            measurements.push(measurement);
+        }
+    }
+
+    if let Some(path) = json {
+        fs::write(path, serde_json::to_string_pretty(&measurements)?)
+            .with_context(|| format!("failed to write {}", path.display()))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use crate::{input::Input, locate_error, InputArgs};
+use advent_of_code_12::{Nonogram, Records, Solutions, Unfold};
+use anyhow::{Context, Result};
+use clap::Subcommand;
+use common::Solution;
+use std::{fs, path::PathBuf};
+
+const DAY: u8 = 12;
+
+#[derive(Subcommand)]
+pub enum Tool {
+    /// Solve both parts, unfolding the records as configured
+    Solve {
+        #[command(flatten)]
+        input: InputArgs,
+
+        /// Number of copies of each record after unfolding it
+        #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u64).range(1..))]
+        factor: u64,
+
+        /// Spring between the copies of a record
+        #[arg(long, default_value_t = '?')]
+        joiner: char,
+
+        /// Show the number of records solved so far
+        #[arg(long)]
+        progress: bool,
+    },
+    /// List the arrangements of the springs of a record
+    Arrangements {
+        #[command(flatten)]
+        input: InputArgs,
+
+        /// Line of the record in the input
+        #[arg(long, value_parser = clap::value_parser!(u64).range(1..))]
+        line: u64,
+
+        /// Number of arrangements to skip
+        #[arg(long, default_value_t = 0)]
+        offset: u64,
+
+        /// Maximum number of arrangements to list
+        #[arg(long, default_value_t = 100)]
+        limit: u64,
+    },
+    /// Solve a nonogram, given the groups of its rows and then of its columns
+    Nonogram {
+        /// File with a line of groups per row and then per column, separated by an empty line
+        file: PathBuf,
+    },
+}
+
+pub fn run(tool: Tool) -> Result<()> {
+    match tool {
+        Tool::Solve {
+            input,
+            factor,
+            joiner,
+            progress,
+        } => {
+            let mut records = parse(&Input::new(input.input, input.example))?
+                .with_unfold(Unfold::new(factor as usize, joiner)?);
+            if progress {
+                records = records.with_progress(|done, total| {
+                    eprint!("\rrecords {done}/{total}");
+                    if done == total {
+                        eprintln!();
+                    }
+                });
+            }
+            println!("Task 1: {}", records.part1()?);
+            println!("Task 2: {}", records.part2()?);
+        }
+        Tool::Arrangements {
+            input,
+            line,
+            offset,
+            limit,
+        } => {
+            let records = parse(&Input::new(input.input, input.example))?;
+            let arrangements = records.arrangements(line as usize - 1)?;
+            for arrangement in arrangements.offset(offset).limit(limit) {
+                println!("{arrangement:?}");
+            }
+        }
+        Tool::Nonogram { file } => {
+            let content = fs::read_to_string(&file)
+                .with_context(|| format!("failed to read {}", file.display()))?;
+            let nonogram = Nonogram::parse(&content).map_err(|error| error.with_file(&file))?;
+            match nonogram.solve() {
+                Solutions::None => println!("no solution"),
+                Solutions::Unique(picture) => print!("unique solution\n{picture}"),
+                Solutions::Multiple(first, second) => {
+                    print!("multiple solutions, e.g.\n{first}\n{second}")
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn parse(input: &Input) -> Result<Records> {
+    let content = input.read(DAY)?;
+    Records::parse(&content).map_err(|error| locate_error(error, input, DAY))
+}
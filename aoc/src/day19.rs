@@ -0,0 +1,175 @@
+use crate::{input::Input, locate_error, InputArgs};
+use advent_of_code_19::{System, RATINGS};
+use anyhow::{bail, Context, Result};
+use clap::Subcommand;
+use common::Solution;
+use std::{fs, ops::RangeInclusive, path::PathBuf};
+
+const DAY: u8 = 19;
+
+#[derive(Subcommand)]
+pub enum Tool {
+    /// Report problems of the workflows, fatal ones as errors and the others as warnings
+    Validate {
+        #[command(flatten)]
+        input: InputArgs,
+
+        /// Ratings of each category to look for shadowed rules in, `<min>..=<max>`
+        #[arg(long, value_parser = parse_ratings)]
+        ratings: Option<RangeInclusive<i32>>,
+    },
+    /// Show the rules that sorted each part
+    Explain {
+        #[command(flatten)]
+        input: InputArgs,
+
+        /// Number of the part in the input, explains all parts if omitted
+        #[arg(long, value_parser = clap::value_parser!(u64).range(1..))]
+        part: Option<u64>,
+    },
+    /// Compile the workflows into a decision tree and render it as Graphviz DOT
+    Dot {
+        #[command(flatten)]
+        input: InputArgs,
+
+        /// Ratings of each category to compile the workflows for, `<min>..=<max>`
+        #[arg(long, value_parser = parse_ratings)]
+        ratings: Option<RangeInclusive<i32>>,
+
+        /// File to write the graph to instead of stdout
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// List the ranges of ratings that are accepted
+    Accepted {
+        #[command(flatten)]
+        input: InputArgs,
+
+        /// Ratings of each category to compile the workflows for, `<min>..=<max>`
+        #[arg(long, value_parser = parse_ratings)]
+        ratings: Option<RangeInclusive<i32>>,
+    },
+    /// Write the workflows and parts back in a normalised form, the workflows sorted by label
+    Format {
+        #[command(flatten)]
+        input: InputArgs,
+
+        /// Read the input as JSON instead of the syntax of the puzzle
+        #[arg(long)]
+        from_json: bool,
+
+        /// Write JSON instead of the syntax of the puzzle
+        #[arg(long)]
+        to_json: bool,
+    },
+}
+
+pub fn run(tool: Tool) -> Result<()> {
+    match tool {
+        Tool::Validate { input, ratings } => {
+            let input = Input::new(input.input, input.example);
+            let content = input.read(DAY)?;
+            let system = System::parse_unchecked(&content)
+                .map_err(|error| locate_error(error.into(), &input, DAY))?;
+            let issues = system.issues(ratings.unwrap_or(RATINGS));
+            for issue in &issues {
+                let level = if issue.is_fatal() { "error" } else { "warning" };
+                println!("{level}: {issue}");
+            }
+            let fatal = issues.iter().filter(|issue| issue.is_fatal()).count();
+            if fatal > 0 {
+                bail!("{fatal} of {} issues are fatal", issues.len());
+            }
+            if issues.is_empty() {
+                println!("no issues");
+            }
+        }
+        Tool::Explain { input, part } => {
+            let system = parse(&Input::new(input.input, input.example))?;
+            let traces = system.explain();
+            match part {
+                Some(part) => match traces.get(part as usize - 1) {
+                    Some(trace) => println!("{trace}"),
+                    None => bail!("there are only {} parts", traces.len()),
+                },
+                None => {
+                    for trace in traces {
+                        println!("{trace}");
+                    }
+                }
+            }
+        }
+        Tool::Dot {
+            input,
+            ratings,
+            output,
+        } => {
+            let system = parse(&Input::new(input.input, input.example))?;
+            let dot = system.compile(ratings.unwrap_or(RATINGS)).to_dot();
+            match output {
+                Some(path) => fs::write(&path, dot)
+                    .with_context(|| format!("failed to write {}", path.display()))?,
+                None => print!("{dot}"),
+            }
+        }
+        Tool::Accepted { input, ratings } => {
+            let system = parse(&Input::new(input.input, input.example))?;
+            let tree = system.compile(ratings.unwrap_or(RATINGS));
+            for parts in tree.accepted() {
+                println!("{parts}: {}", count(parts.combinations()));
+            }
+            println!(
+                "{} decisions, {} accepted",
+                tree.decisions(),
+                count(tree.combinations().ok())
+            );
+        }
+        Tool::Format {
+            input,
+            from_json,
+            to_json,
+        } => {
+            let input = Input::new(input.input, input.example);
+            let system = if from_json {
+                System::from_json(&input.read(DAY)?)?
+            } else {
+                parse(&input)?
+            };
+            if to_json {
+                println!("{}", system.to_json());
+            } else {
+                print!("{system}");
+            }
+        }
+    }
+    Ok(())
+}
+
+fn parse(input: &Input) -> Result<System> {
+    let content = input.read(DAY)?;
+    System::parse(&content).map_err(|error| locate_error(error, input, DAY))
+}
+
+/// Number of combinations, `None` if there are more than fit into a `usize`
+fn count(combinations: Option<usize>) -> String {
+    combinations.map_or_else(|| format!("more than {}", usize::MAX), |n| n.to_string())
+}
+
+/// Parses `<min>..=<max>`
+fn parse_ratings(text: &str) -> Result<RangeInclusive<i32>, String> {
+    let (min, max) = text
+        .split_once("..=")
+        .ok_or_else(|| String::from("expected `<min>..=<max>`"))?;
+    let min = min
+        .trim()
+        .parse::<i32>()
+        .map_err(|error| error.to_string())?;
+    let max = max
+        .trim()
+        .parse::<i32>()
+        .map_err(|error| error.to_string())?;
+    if min > max {
+        return Err(format!("{min} is greater than {max}"));
+    }
+    Ok(min..=max)
+}
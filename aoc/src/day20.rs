@@ -0,0 +1,137 @@
+use crate::{input::Input, locate_error, InputArgs};
+use advent_of_code_20::{Modules, Trace};
+use anyhow::{Context, Result};
+use clap::Subcommand;
+use common::Solution;
+use std::{
+    fs::{self, File},
+    io::{self, BufReader, BufWriter, Write},
+    path::PathBuf,
+};
+
+const DAY: u8 = 20;
+
+#[derive(Subcommand)]
+pub enum Tool {
+    /// Solve both parts with the same modules, resetting them in between
+    Solve {
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Show the cycles of the inputs of the conjunction feeding `rx`
+    Cycles {
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Record the pulses of button presses as JSON lines
+    Trace {
+        #[command(flatten)]
+        input: InputArgs,
+
+        /// Number of button presses
+        #[arg(long, default_value_t = 1)]
+        presses: usize,
+
+        /// Only record the pulses sent or received by this module
+        #[arg(long)]
+        module: Option<String>,
+
+        /// File to write the pulses to instead of stdout
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Render the wiring of the modules as Graphviz DOT
+    Dot {
+        #[command(flatten)]
+        input: InputArgs,
+
+        /// Draw the independent sub-networks in boxes of their own
+        #[arg(long)]
+        cluster: bool,
+
+        /// File to write the graph to instead of stdout
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Show the state of the modules after a press by replaying a recorded trace
+    Replay {
+        #[command(flatten)]
+        input: InputArgs,
+
+        /// Unfiltered trace written by `trace`
+        #[arg(long)]
+        trace: PathBuf,
+
+        /// Button press to restore the state after
+        #[arg(long)]
+        press: usize,
+    },
+}
+
+pub fn run(tool: Tool) -> Result<()> {
+    match tool {
+        Tool::Solve { input } => {
+            let mut modules = parse(&Input::new(input.input, input.example))?;
+            println!("Task 1: {}", modules.warm_up());
+            println!("Task 2: {}", modules.cycles()?.presses()?);
+        }
+        Tool::Cycles { input } => {
+            let mut modules = parse(&Input::new(input.input, input.example))?;
+            println!("{}", modules.cycles()?);
+        }
+        Tool::Trace {
+            input,
+            presses,
+            module,
+            output,
+        } => {
+            let mut modules = parse(&Input::new(input.input, input.example))?;
+            let mut trace = modules.trace(presses);
+            if let Some(module) = module {
+                trace = trace.filter(&module);
+            }
+            match output {
+                Some(path) => {
+                    let file = File::create(&path)
+                        .with_context(|| format!("failed to write {}", path.display()))?;
+                    let mut writer = BufWriter::new(file);
+                    trace.write_json_lines(&mut writer)?;
+                    writer.flush()?;
+                }
+                None => trace.write_json_lines(io::stdout().lock())?,
+            }
+        }
+        Tool::Dot {
+            input,
+            cluster,
+            output,
+        } => {
+            let modules = parse(&Input::new(input.input, input.example))?;
+            let dot = modules.to_dot(cluster);
+            match output {
+                Some(path) => fs::write(&path, dot)
+                    .with_context(|| format!("failed to write {}", path.display()))?,
+                None => print!("{dot}"),
+            }
+        }
+        Tool::Replay {
+            input,
+            trace,
+            press,
+        } => {
+            let mut modules = parse(&Input::new(input.input, input.example))?;
+            let file = File::open(&trace)
+                .with_context(|| format!("failed to read {}", trace.display()))?;
+            modules.replay(&Trace::read_json_lines(BufReader::new(file))?, press)?;
+            for (module, state) in modules.states() {
+                println!("{module}: {state}");
+            }
+        }
+    }
+    Ok(())
+}
+
+fn parse(input: &Input) -> Result<Modules> {
+    let content = input.read(DAY)?;
+    Modules::parse(&content).map_err(|error| locate_error(error, input, DAY))
+}
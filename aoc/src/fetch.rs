@@ -0,0 +1,163 @@
+use anyhow::{anyhow, bail, Context, Result};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+/// Server of the real puzzle inputs
+const BASE_URL: &str = "https://adventofcode.com";
+const YEAR: u32 = 2023;
+/// Identifies the tool to the server, as asked for by the puzzle author
+const USER_AGENT: &str = "github.com/JulienKulbe/advent_of_code_2023";
+
+/// Downloads puzzle inputs, every input is only downloaded once and cached afterwards
+pub struct Fetcher {
+    base_url: String,
+    session: Option<String>,
+}
+
+impl Fetcher {
+    pub fn new(base_url: impl Into<String>, session: Option<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_owned(),
+            session,
+        }
+    }
+
+    /// Configures the fetcher by the environment.
+    ///
+    /// The base URL is read from `AOC_BASE_URL`, e.g. to use a local stand-in server.
+    /// The session token is read from `AOC_SESSION` or from the `.session` file of the workspace.
+    pub fn from_env() -> Result<Self> {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| BASE_URL.to_owned());
+        let session = match env::var("AOC_SESSION") {
+            Ok(session) => Some(session),
+            Err(_) => {
+                let path = session_path();
+                path.exists()
+                    .then(|| fs::read_to_string(&path))
+                    .transpose()
+                    .with_context(|| format!("failed to read {}", path.display()))?
+            }
+        };
+        Ok(Self::new(
+            base_url,
+            session.map(|session| session.trim().to_owned()),
+        ))
+    }
+
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_owned();
+        self
+    }
+
+    fn url(&self, day: u8) -> String {
+        format!("{}/{YEAR}/day/{day}/input", self.base_url)
+    }
+
+    /// Caches the input of a day at the path, returns `false` if it was cached already
+    pub fn fetch(&self, day: u8, path: &Path) -> Result<bool> {
+        if path.exists() {
+            return Ok(false);
+        }
+
+        let session = self.session.as_deref().ok_or_else(|| {
+            anyhow!("no session token to fetch the input of day {day}, set AOC_SESSION")
+        })?;
+        let url = self.url(day);
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={session}"))
+            .set("User-Agent", USER_AGENT)
+            .call()
+            .with_context(|| format!("failed to fetch {url}"))?;
+        let input = response
+            .into_string()
+            .with_context(|| format!("failed to read the response of {url}"))?;
+        if input.is_empty() {
+            bail!("{url} returned an empty input");
+        }
+
+        // write to a temporary file first, an interrupted download must not be cached
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory)?;
+        }
+        let partial = path.with_extension("partial");
+        fs::write(&partial, input)
+            .with_context(|| format!("failed to write {}", partial.display()))?;
+        fs::rename(&partial, path)
+            .with_context(|| format!("failed to write {}", path.display()))?;
+        Ok(true)
+    }
+}
+
+fn session_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .join(".session")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    /// Serves a single input, returns its base URL and the request lines it received
+    fn serve(input: &'static str) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = Vec::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim_end().is_empty() {
+                    break;
+                }
+                request.push(line.trim_end().to_owned());
+            }
+
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{input}",
+                input.len()
+            );
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            request
+        });
+        (base_url, server)
+    }
+
+    #[test]
+    fn fetch_once() -> Result<()> {
+        let (base_url, server) = serve("1abc2\n");
+        let directory = env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+        let path = directory.join("input.txt");
+        let fetcher = Fetcher::new(base_url, Some(String::from("token")));
+
+        assert!(fetcher.fetch(1, &path)?);
+        // the stand-in server is gone, a second request would fail
+        assert!(!fetcher.fetch(1, &path)?);
+        assert_eq!(fs::read_to_string(&path)?, "1abc2\n");
+
+        let request = server.join().unwrap();
+        assert_eq!(request[0], "GET /2023/day/1/input HTTP/1.1");
+        assert!(request.contains(&String::from("Cookie: session=token")));
+
+        fs::remove_dir_all(directory)?;
+        Ok(())
+    }
+
+    #[test]
+    fn missing_session() {
+        let fetcher = Fetcher::new("http://127.0.0.1:9", None);
+        let path = env::temp_dir().join("aoc-fetch-missing-session.txt");
+        assert!(fetcher.fetch(1, &path).is_err());
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,121 @@
+use crate::fetch::Fetcher;
+use anyhow::{anyhow, Context, Result};
+use std::{
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Source of the puzzle input
+pub enum Input {
+    /// The real puzzle input of the day, fetched if it is missing
+    Puzzle,
+    /// One of the examples from the puzzle text
+    Example(u32),
+    /// Any other file
+    File(PathBuf),
+    Stdin,
+}
+
+impl Input {
+    pub fn new(input: Option<PathBuf>, example: Option<u32>) -> Self {
+        match (input, example) {
+            (Some(path), _) if path == Path::new("-") => Input::Stdin,
+            (Some(path), _) => Input::File(path),
+            (None, Some(example)) => Input::Example(example),
+            (None, None) => Input::Puzzle,
+        }
+    }
+
+    pub fn is_puzzle(&self) -> bool {
+        matches!(self, Input::Puzzle)
+    }
+
+    pub fn is_example(&self) -> bool {
+        matches!(self, Input::Example(_))
+    }
+
+    /// File of the input, `None` for stdin
+    pub fn path(&self, day: u8) -> Option<PathBuf> {
+        match self {
+            Input::Puzzle => Some(puzzle_path(day)),
+            Input::Example(example) => find_example(day, *example).ok(),
+            Input::File(path) => Some(path.clone()),
+            Input::Stdin => None,
+        }
+    }
+
+    pub fn read(&self, day: u8) -> Result<String> {
+        match self {
+            Input::Puzzle => {
+                let path = puzzle_path(day);
+                Fetcher::from_env()?.fetch(day, &path)?;
+                read_file(&path)
+            }
+            Input::Example(example) => read_file(&find_example(day, *example)?),
+            Input::File(path) => read_file(path),
+            Input::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+pub fn day_directory(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .join(format!("advent_of_code_{day:02}"))
+}
+
+/// File of the puzzle input of a day, also its cache when fetching it
+pub fn puzzle_path(day: u8) -> PathBuf {
+    day_directory(day).join("input.txt")
+}
+
+/// Lists the examples of a day, ordered by their number.
+///
+/// The number is the suffix of the file name: `input_small.txt` is the first example,
+/// `input_small2.txt` or `input_small_2.txt` the second one.
+pub fn examples(day: u8) -> Result<Vec<(u32, PathBuf)>> {
+    let mut examples = Vec::new();
+    for entry in fs::read_dir(day_directory(day))? {
+        let path = entry?.path();
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        let Some(suffix) = name
+            .strip_prefix("input_small")
+            .and_then(|name| name.strip_suffix(".txt"))
+        else {
+            continue;
+        };
+
+        let suffix = suffix.trim_start_matches('_');
+        let number = if suffix.is_empty() {
+            1
+        } else if let Ok(number) = suffix.parse::<u32>() {
+            number
+        } else {
+            continue;
+        };
+        examples.push((number, path));
+    }
+
+    examples.sort();
+    Ok(examples)
+}
+
+fn find_example(day: u8, example: u32) -> Result<PathBuf> {
+    examples(day)?
+        .into_iter()
+        .find(|(number, _)| *number == example)
+        .map(|(_, path)| path)
+        .ok_or_else(|| anyhow!("day {day} has no example {example}"))
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))
+}
@@ -0,0 +1,127 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fs, path::PathBuf};
+
+/// Answers of the puzzle inputs, recorded to notice when a solver changes its answer
+#[derive(Default, Serialize, Deserialize)]
+pub struct Ledger(BTreeMap<String, Entry>);
+
+#[derive(Default, Serialize, Deserialize)]
+struct Entry {
+    /// Hash of the input the answers were recorded for
+    #[serde(default)]
+    input: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<String>,
+    /// Parts that have no solution yet
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    unsolved: Vec<u8>,
+    /// Parts that are too slow or crash, they are never solved while verifying
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    skip: Vec<u8>,
+}
+
+impl Entry {
+    fn answer(&mut self, part: u8) -> &mut Option<String> {
+        match part {
+            1 => &mut self.part1,
+            _ => &mut self.part2,
+        }
+    }
+}
+
+/// Result of comparing an answer with the ledger
+#[derive(Debug, PartialEq, Eq)]
+pub enum Status {
+    Correct,
+    Changed { recorded: String },
+    New,
+    Unsolved,
+}
+
+impl Ledger {
+    pub fn path() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .unwrap()
+            .join("answers.toml")
+    }
+
+    pub fn load() -> Result<Self> {
+        let path = Self::path();
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        toml::from_str(&content).with_context(|| format!("invalid ledger {}", path.display()))
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = Self::path();
+        fs::write(&path, toml::to_string(self)?)
+            .with_context(|| format!("failed to write {}", path.display()))
+    }
+
+    pub fn is_skipped(&self, day: u8, part: u8) -> bool {
+        self.0
+            .get(&key(day))
+            .is_some_and(|entry| entry.skip.contains(&part))
+    }
+
+    /// Compares an answer with the recorded one, `None` is the answer of an unsolved part
+    pub fn check(&self, day: u8, input: &str, part: u8, answer: Option<&str>) -> Status {
+        let recorded = self
+            .0
+            .get(&key(day))
+            .filter(|entry| entry.input == hash(input));
+        let recorded = recorded.and_then(|entry| match part {
+            1 => entry.part1.as_deref(),
+            _ => entry.part2.as_deref(),
+        });
+
+        match (answer, recorded) {
+            (Some(answer), Some(recorded)) if answer == recorded => Status::Correct,
+            (Some(_), None) => Status::New,
+            (None, None) => Status::Unsolved,
+            (_, Some(recorded)) => Status::Changed {
+                recorded: recorded.to_owned(),
+            },
+        }
+    }
+
+    pub fn record(&mut self, day: u8, input: &str, part: u8, answer: Option<&str>) {
+        let input = hash(input);
+        let entry = self.0.entry(key(day)).or_default();
+        if entry.input != input {
+            // the answers of a different input are worthless
+            *entry = Entry {
+                input,
+                skip: std::mem::take(&mut entry.skip),
+                ..Default::default()
+            };
+        }
+
+        *entry.answer(part) = answer.map(String::from);
+        entry.unsolved.retain(|&unsolved| unsolved != part);
+        if answer.is_none() {
+            entry.unsolved.push(part);
+            entry.unsolved.sort();
+        }
+    }
+}
+
+fn key(day: u8) -> String {
+    format!("day{day:02}")
+}
+
+/// FNV-1a hash, stable between compiler versions unlike the hasher of the std
+fn hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{hash:016x}")
+}
@@ -0,0 +1,309 @@
+mod bench;
+mod day12;
+mod day19;
+mod day20;
+mod fetch;
+mod input;
+mod ledger;
+
+use anyhow::{anyhow, bail, Result};
+use clap::{Args, Parser, Subcommand};
+use common::{parse::ParseError, Solution, Unsolved};
+use fetch::Fetcher;
+use input::Input;
+use ledger::{Ledger, Status};
+use std::{path::PathBuf, process::ExitCode};
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2023")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve the puzzle of a single day
+    Run {
+        /// Day of the puzzle
+        #[arg(long)]
+        day: u8,
+
+        /// Part of the puzzle, solves both parts if omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        #[command(flatten)]
+        input: InputArgs,
+
+        /// Record the answers of the puzzle input in the ledger
+        #[arg(long)]
+        record: bool,
+    },
+    /// Compare the answers of the puzzle inputs with the ledger
+    Verify {
+        /// Day of the puzzle, verifies all days if omitted
+        #[arg(long)]
+        day: Option<u8>,
+
+        /// Record the answers in the ledger, accepting changed ones
+        #[arg(long)]
+        record: bool,
+    },
+    /// Measure the time to parse the puzzle inputs and solve their parts
+    Bench {
+        /// Day of the puzzle, measures all days if omitted
+        #[arg(long)]
+        day: Option<u8>,
+
+        /// Number of times each day is parsed and solved
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        runs: u64,
+
+        /// Also write the measurements as JSON to this file
+        #[arg(long)]
+        json: Option<PathBuf>,
+    },
+    /// Download the puzzle inputs that are not cached yet
+    Fetch {
+        /// Day of the puzzle, fetches the inputs of all solved days if omitted
+        #[arg(long)]
+        day: Option<u8>,
+
+        /// Server to fetch from instead of `AOC_BASE_URL` or adventofcode.com
+        #[arg(long)]
+        base_url: Option<String>,
+    },
+    /// Inspect the springs of day 12
+    Day12 {
+        #[command(subcommand)]
+        tool: day12::Tool,
+    },
+    /// Inspect the workflows of day 19
+    Day19 {
+        #[command(subcommand)]
+        tool: day19::Tool,
+    },
+    /// Inspect the modules of day 20
+    Day20 {
+        #[command(subcommand)]
+        tool: day20::Tool,
+    },
+}
+
+#[derive(Args)]
+#[group(multiple = false)]
+struct InputArgs {
+    /// Puzzle input file, `-` reads the input from stdin
+    #[arg(long)]
+    input: Option<PathBuf>,
+
+    /// Solve the n-th example of the puzzle text instead of the puzzle input
+    #[arg(long)]
+    example: Option<u32>,
+}
+
+/// Parsed puzzle of any day
+trait Puzzle {
+    fn solve(&self, part: u8) -> Result<String>;
+}
+
+impl<S: Solution> Puzzle for S {
+    fn solve(&self, part: u8) -> Result<String> {
+        match part {
+            1 => self.part1().map(|answer| answer.to_string()),
+            _ => self.part2().map(|answer| answer.to_string()),
+        }
+    }
+}
+
+type Parse = fn(&str, bool) -> Result<Box<dyn Puzzle>>;
+
+fn parse<S: Solution + 'static>(input: &str, example: bool) -> Result<Box<dyn Puzzle>> {
+    let puzzle = if example {
+        S::parse_example(input)?
+    } else {
+        S::parse(input)?
+    };
+    Ok(Box::new(puzzle))
+}
+
+fn get_parser(day: u8) -> Option<Parse> {
+    let parser: Parse = match day {
+        1 => parse::<advent_of_code_01::CalibrationDocument>,
+        2 => parse::<advent_of_code_02::Games>,
+        3 => parse::<advent_of_code_03::Schematic>,
+        4 => parse::<advent_of_code_04::Scratchcards>,
+        5 => parse::<advent_of_code_05::Almanac>,
+        6 => parse::<advent_of_code_06::Races>,
+        7 => parse::<advent_of_code_07::Hands>,
+        8 => parse::<advent_of_code_08::Map>,
+        9 => parse::<advent_of_code_09::Report>,
+        10 => parse::<advent_of_code_10::Maze>,
+        11 => parse::<advent_of_code_11::Image>,
+        12 => parse::<advent_of_code_12::Records>,
+        13 => parse::<advent_of_code_13::Patterns>,
+        14 => parse::<advent_of_code_14::Platform>,
+        15 => parse::<advent_of_code_15::Sequence>,
+        16 => parse::<advent_of_code_16::Map>,
+        17 => parse::<advent_of_code_17::Map>,
+        18 => parse::<advent_of_code_18::Instructions>,
+        19 => parse::<advent_of_code_19::System>,
+        20 => parse::<advent_of_code_20::Modules>,
+        21 => parse::<advent_of_code_21::Garden>,
+        _ => return None,
+    };
+    Some(parser)
+}
+
+/// Parses the input of a day, parse errors are located in the file of the input
+fn parse_input(parse: Parse, content: &str, input: &Input, day: u8) -> Result<Box<dyn Puzzle>> {
+    parse(content, input.is_example()).map_err(|error| locate_error(error, input, day))
+}
+
+/// Adds the file of the input to a parse error
+fn locate_error(error: anyhow::Error, input: &Input, day: u8) -> anyhow::Error {
+    match error.downcast::<ParseError>() {
+        Ok(error) => match input.path(day) {
+            Some(path) => error.with_file(path).into(),
+            None => error.with_file("<stdin>").into(),
+        },
+        Err(error) => error,
+    }
+}
+
+/// Solves a part, `None` if the part has no solution yet
+fn solve(puzzle: &dyn Puzzle, part: u8) -> Result<Option<String>> {
+    match puzzle.solve(part) {
+        Ok(answer) => Ok(Some(answer)),
+        Err(error) if error.is::<Unsolved>() => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+fn run(day: u8, part: Option<u8>, input: Input, record: bool) -> Result<()> {
+    if record && !input.is_puzzle() {
+        bail!("only answers of the puzzle input can be recorded");
+    }
+
+    let parse = get_parser(day).ok_or_else(|| anyhow!("day {day} is not solved yet"))?;
+    let content = input.read(day)?;
+    let puzzle = parse_input(parse, &content, &input, day)?;
+    let mut ledger = Ledger::load()?;
+
+    for part in part.map_or(vec![1, 2], |part| vec![part]) {
+        let answer = solve(puzzle.as_ref(), part)?;
+
+        if input.is_puzzle() {
+            let status = ledger.check(day, &content, part, answer.as_deref());
+            if let Status::Changed { recorded } = status {
+                eprintln!("warning: day {day} part {part} was answered with {recorded} before");
+            }
+            if record {
+                ledger.record(day, &content, part, answer.as_deref());
+                ledger.save()?;
+            }
+        }
+
+        match answer {
+            Some(answer) => println!("Task {part}: {answer}"),
+            None => bail!("day {day} part {part} is not solved yet"),
+        }
+    }
+
+    Ok(())
+}
+
+fn verify(day: Option<u8>, record: bool) -> Result<()> {
+    let mut ledger = Ledger::load()?;
+    let mut changed = 0;
+
+    for day in day.map_or_else(|| (1..=25).collect(), |day| vec![day]) {
+        let Some(parse) = get_parser(day) else {
+            continue;
+        };
+        let input = Input::Puzzle.read(day)?;
+        let puzzle = parse_input(parse, &input, &Input::Puzzle, day)?;
+
+        for part in 1..=2 {
+            if ledger.is_skipped(day, part) {
+                println!("Day {day:02} part {part}: skipped");
+                continue;
+            }
+
+            let answer = solve(puzzle.as_ref(), part)?;
+            let status = ledger.check(day, &input, part, answer.as_deref());
+            let shown = answer.as_deref().unwrap_or("not solved yet");
+            match status {
+                Status::Correct | Status::Unsolved => println!("Day {day:02} part {part}: {shown}"),
+                Status::New => println!("Day {day:02} part {part}: {shown} (new)"),
+                Status::Changed { recorded } => {
+                    changed += 1;
+                    println!("Day {day:02} part {part}: {shown} (changed, recorded {recorded})");
+                }
+            }
+
+            if record {
+                ledger.record(day, &input, part, answer.as_deref());
+            }
+        }
+    }
+
+    if record {
+        ledger.save()?;
+    } else if changed > 0 {
+        bail!("{changed} answers differ from the ledger");
+    }
+    Ok(())
+}
+
+fn fetch(day: Option<u8>, base_url: Option<&str>) -> Result<()> {
+    let mut fetcher = Fetcher::from_env()?;
+    if let Some(base_url) = base_url {
+        fetcher = fetcher.with_base_url(base_url);
+    }
+
+    let days = day.map_or_else(
+        || (1..=25).filter(|&day| get_parser(day).is_some()).collect(),
+        |day| vec![day],
+    );
+    for day in days {
+        let path = input::puzzle_path(day);
+        if fetcher.fetch(day, &path)? {
+            println!("Day {day:02}: fetched {}", path.display());
+        } else {
+            println!("Day {day:02}: cached");
+        }
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            record,
+        } => run(day, part, Input::new(input.input, input.example), record),
+        Command::Verify { day, record } => verify(day, record),
+        Command::Bench { day, runs, json } => bench::bench(day, runs as usize, json.as_deref()),
+        Command::Fetch { day, base_url } => fetch(day, base_url.as_deref()),
+        Command::Day12 { tool } => day12::run(tool),
+        Command::Day19 { tool } => day19::run(tool),
+        Command::Day20 { tool } => day20::run(tool),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            match error.downcast_ref::<ParseError>() {
+                Some(error) => eprintln!("{}", error.diagnostic()),
+                None => eprintln!("Error: {error:?}"),
+            }
+            ExitCode::FAILURE
+        }
+    }
+}
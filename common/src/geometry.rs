@@ -0,0 +1,177 @@
+/// Direction on a map with the y axis pointing down
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All directions clockwise, starting with up
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+        }
+    }
+
+    pub fn reverse(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::Left | Direction::Right)
+    }
+
+    pub fn is_vertical(self) -> bool {
+        !self.is_horizontal()
+    }
+
+    /// Change of the coordinates of a single step
+    pub fn offset(self) -> (i64, i64) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Right => (1, 0),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+        }
+    }
+}
+
+/// Position on a grid, it cannot leave the grid to the top or left
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Position {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Position {
+    pub fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    /// Steps into the direction, `None` when stepping beyond the top or left edge
+    pub fn step(self, direction: Direction) -> Option<Self> {
+        self.step_by(direction, 1)
+    }
+
+    pub fn step_by(self, direction: Direction, distance: usize) -> Option<Self> {
+        let position = match direction {
+            Direction::Up => Self::new(self.x, self.y.checked_sub(distance)?),
+            Direction::Right => Self::new(self.x.checked_add(distance)?, self.y),
+            Direction::Down => Self::new(self.x, self.y.checked_add(distance)?),
+            Direction::Left => Self::new(self.x.checked_sub(distance)?, self.y),
+        };
+        Some(position)
+    }
+
+    /// Steps into the direction, `None` when leaving a grid of the given size
+    pub fn step_within(self, direction: Direction, width: usize, height: usize) -> Option<Self> {
+        self.step(direction)
+            .filter(|position| position.x < width && position.y < height)
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+/// Point on an unbounded plane
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    pub fn step(self, direction: Direction) -> Self {
+        self.step_by(direction, 1)
+    }
+
+    pub fn step_by(self, direction: Direction, distance: i64) -> Self {
+        let (dx, dy) = direction.offset();
+        Self::new(self.x + dx * distance, self.y + dy * distance)
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl From<Position> for Point {
+    fn from(position: Position) -> Self {
+        Point::new(position.x as i64, position.y as i64)
+    }
+}
+
+impl TryFrom<Point> for Position {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(point: Point) -> Result<Self, Self::Error> {
+        Ok(Position::new(point.x.try_into()?, point.y.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turn() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.reverse(), Direction::Right);
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+        }
+    }
+
+    #[test]
+    fn step() {
+        let position = Position::new(0, 1);
+        assert_eq!(position.step(Direction::Up), Some(Position::new(0, 0)));
+        assert_eq!(position.step(Direction::Left), None);
+        assert_eq!(position.step_within(Direction::Down, 1, 2), None);
+        assert_eq!(
+            Point::new(0, 0).step_by(Direction::Left, 3),
+            Point::new(-3, 0)
+        );
+    }
+
+    #[test]
+    fn manhattan_distance() {
+        assert_eq!(
+            Position::new(1, 6).manhattan_distance(Position::new(5, 11)),
+            9
+        );
+        assert_eq!(Point::new(-1, 2).manhattan_distance(Point::new(2, -2)), 7);
+    }
+}
@@ -0,0 +1,301 @@
+use crate::{
+    geometry::{Direction, Position},
+    parse::ParseError,
+};
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+/// Rectangular grid of cells, addressed by positions with `(0, 0)` in the top left corner
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its cells, row by row
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "cells do not fill the grid");
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, cell: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, height, vec![cell; width * height])
+    }
+
+    /// Parses a grid with one row per line and one cell per character
+    pub fn parse(input: &str) -> Result<Self, ParseError>
+    where
+        T: TryFrom<char>,
+        T::Error: fmt::Display,
+    {
+        Self::parse_with(input, T::try_from)
+    }
+
+    pub fn parse_with<E: fmt::Display>(
+        input: &str,
+        mut parse: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = 0;
+        let mut height = 0;
+        for line in input.lines() {
+            for (index, c) in line.char_indices() {
+                let cell = parse(c).map_err(|error| {
+                    ParseError::at(input, &line[index..index + c.len_utf8()], error)
+                })?;
+                cells.push(cell);
+            }
+
+            let row_width = line.chars().count();
+            if height == 0 {
+                width = row_width;
+            } else if row_width != width {
+                let message = format!("row has {row_width} cells, expected {width}");
+                return Err(ParseError::at(input, line, message));
+            }
+            height += 1;
+        }
+
+        if width == 0 {
+            return Err(ParseError::at(
+                input,
+                input.trim_end(),
+                "expected at least one cell",
+            ));
+        }
+        Ok(Self::new(width, height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        position.x < self.width && position.y < self.height
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[self.index(position)])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if self.contains(position) {
+            let index = self.index(position);
+            Some(&mut self.cells[index])
+        } else {
+            None
+        }
+    }
+
+    fn index(&self, position: Position) -> usize {
+        position.y * self.width + position.x
+    }
+
+    /// Positions of all cells, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Position::new(x, y)))
+    }
+
+    /// All cells with their position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1)).take(self.height)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells
+            .iter()
+            .skip(x)
+            .step_by(self.width.max(1))
+            .take(self.height)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Steps from the position into the direction, `None` when leaving the grid
+    pub fn step(&self, position: Position, direction: Direction) -> Option<Position> {
+        position.step_within(direction, self.width, self.height)
+    }
+
+    /// Positions next to the given one within the grid, horizontally and vertically
+    pub fn neighbours(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// Positions next to the given one within the grid, including the diagonal ones
+    pub fn neighbours_diagonal(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        const OFFSETS: [(isize, isize); 8] = [
+            (-1, -1),
+            (0, -1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+            (0, 1),
+            (-1, 1),
+            (-1, 0),
+        ];
+        OFFSETS.iter().filter_map(move |(dx, dy)| {
+            let position = Position::new(
+                position.x.checked_add_signed(*dx)?,
+                position.y.checked_add_signed(*dy)?,
+            );
+            self.contains(position).then_some(position)
+        })
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    /// Mirrors the grid at its diagonal, rows become columns
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .flat_map(|x| self.column(x).cloned())
+            .collect();
+        Self::new(self.height, self.width, cells)
+    }
+
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .flat_map(|x| {
+                let mut column = self.column(x).cloned().collect::<Vec<_>>();
+                column.reverse();
+                column
+            })
+            .collect();
+        Self::new(self.height, self.width, cells)
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .rev()
+            .flat_map(|x| self.column(x).cloned())
+            .collect();
+        Self::new(self.height, self.width, cells)
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        assert!(self.contains(position), "{position:?} is outside the grid");
+        &self.cells[self.index(position)]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        assert!(self.contains(position), "{position:?} is outside the grid");
+        let index = self.index(position);
+        &mut self.cells[index]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse("abc\ndef\n").unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Position::new(2, 1)], 'f');
+        assert_eq!(grid.get(Position::new(3, 0)), None);
+        assert!(Grid::<char>::parse("ab\nc").is_err());
+        assert!(Grid::<char>::parse("").is_err());
+        assert!(Grid::<char>::parse("\n\n").is_err());
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = grid();
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            [['a', 'b', 'c'], ['d', 'e', 'f']]
+        );
+        let columns = grid
+            .columns()
+            .map(|column| column.collect::<String>())
+            .collect::<Vec<_>>();
+        assert_eq!(columns, ["ad", "be", "cf"]);
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = grid();
+        assert_eq!(
+            grid.neighbours(Position::new(0, 0)).collect::<Vec<_>>(),
+            [Position::new(1, 0), Position::new(0, 1)]
+        );
+        assert_eq!(grid.neighbours_diagonal(Position::new(2, 0)).count(), 3);
+        assert_eq!(grid.neighbours_diagonal(Position::new(1, 1)).count(), 5);
+        assert_eq!(grid.step(Position::new(2, 1), Direction::Right), None);
+    }
+
+    #[test]
+    fn transform() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+    }
+}
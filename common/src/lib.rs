@@ -0,0 +1,40 @@
+pub mod geometry;
+pub mod grid;
+pub mod parse;
+
+use anyhow::Result;
+use std::{error::Error, fmt};
+
+/// Puzzle of a single day, parsed from its input
+pub trait Solution: Sized {
+    type Answer1: fmt::Display;
+    type Answer2: fmt::Display;
+
+    fn parse(input: &str) -> Result<Self>;
+
+    /// Parses one of the examples of the puzzle text.
+    ///
+    /// Some puzzles use different parameters for their examples than for the real input,
+    /// e.g. fewer steps to walk.
+    fn parse_example(input: &str) -> Result<Self> {
+        Self::parse(input)
+    }
+
+    fn part1(&self) -> Result<Self::Answer1>;
+
+    fn part2(&self) -> Result<Self::Answer2> {
+        Err(Unsolved.into())
+    }
+}
+
+/// Answer and error of a part that has no solution yet
+#[derive(Debug)]
+pub struct Unsolved;
+
+impl fmt::Display for Unsolved {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "not solved yet")
+    }
+}
+
+impl Error for Unsolved {}
@@ -0,0 +1,170 @@
+use std::{
+    error::Error,
+    fmt,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+/// Malformed puzzle input, located by the line and column of the offending text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// File of the input, set by whoever read it
+    pub file: Option<PathBuf>,
+    /// Line of the offending text, starting at 1
+    pub line: usize,
+    /// Column of the offending text in characters, starting at 1
+    pub column: usize,
+    /// The offending text
+    pub text: String,
+    /// The whole line containing the offending text
+    pub source_line: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Error at `text`, a slice of `input`.
+    ///
+    /// A `text` that is not a slice of `input` is searched in it instead.
+    pub fn at(input: &str, text: &str, message: impl fmt::Display) -> Self {
+        let offset = offset_of(input, text)
+            .or_else(|| input.find(text))
+            .unwrap_or(input.len());
+
+        let line_start = input[..offset].rfind('\n').map_or(0, |index| index + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |index| offset + index);
+
+        Self {
+            file: None,
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            text: text.to_owned(),
+            source_line: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_owned(),
+            message: message.to_string(),
+        }
+    }
+
+    /// Error at the missing text at the end of `text`, a slice of `input`
+    pub fn after(input: &str, text: &str, message: impl fmt::Display) -> Self {
+        Self::at(input, &text[text.len()..], message)
+    }
+
+    /// Moves an error found in `text`, a slice of `input`, to its location in `input`
+    pub fn within(mut self, input: &str, text: &str) -> Self {
+        let start = Self::at(input, text, "");
+        if self.line == 1 {
+            self.column += start.column - 1;
+            self.source_line = start.source_line;
+        }
+        self.line += start.line - 1;
+        self
+    }
+
+    pub fn with_file(mut self, file: impl AsRef<Path>) -> Self {
+        self.file = Some(file.as_ref().to_owned());
+        self
+    }
+
+    /// Renders the error with the offending line, underlining the offending text
+    pub fn diagnostic(&self) -> String {
+        let file = self.file.as_deref().map_or_else(
+            || String::from("<input>"),
+            |file| file.display().to_string(),
+        );
+        let number = self.line.to_string();
+        let margin = " ".repeat(number.len());
+        let underline = "^".repeat(self.text.chars().count().max(1));
+
+        format!(
+            "error: {}\n{margin}--> {file}:{}:{}\n{margin} |\n{number} | {}\n{margin} | {}{underline}",
+            self.message,
+            self.line,
+            self.column,
+            self.source_line,
+            " ".repeat(self.column - 1),
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file.display())?;
+        }
+        write!(
+            f,
+            "{}:{}: {} `{}`",
+            self.line, self.column, self.message, self.text
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `text`, a slice of `input`, e.g. a number
+pub fn parse<T: FromStr>(input: &str, text: &str) -> Result<T, ParseError> {
+    text.parse().map_err(|_| {
+        let kind = std::any::type_name::<T>().rsplit("::").next().unwrap();
+        ParseError::at(input, text, format!("expected {kind}"))
+    })
+}
+
+/// Splits `text`, a slice of `input`, once at the delimiter
+pub fn split_once<'a>(
+    input: &str,
+    text: &'a str,
+    delimiter: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(delimiter)
+        .ok_or_else(|| ParseError::after(input, text, format!("expected `{delimiter}`")))
+}
+
+/// Byte offset of `text` within `input`, if it is a slice of it
+fn offset_of(input: &str, text: &str) -> Option<usize> {
+    let start = input.as_ptr() as usize;
+    let offset = (text.as_ptr() as usize).checked_sub(start)?;
+    (offset + text.len() <= input.len()).then_some(offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "Time: 7 15\nDistance: 9 x0\n";
+
+    #[test]
+    fn locate() {
+        let error = ParseError::at(INPUT, &INPUT[23..25], "expected a number");
+        assert_eq!((error.line, error.column), (2, 13));
+        assert_eq!(error.source_line, "Distance: 9 x0");
+        assert_eq!(error.to_string(), "2:13: expected a number `x0`");
+
+        let error = ParseError::at(INPUT, "15", "too fast");
+        assert_eq!((error.line, error.column), (1, 9));
+
+        let line = &INPUT[11..];
+        let error = ParseError::at(line, &line[12..], "expected a number").within(INPUT, line);
+        assert_eq!((error.line, error.column), (2, 13));
+    }
+
+    #[test]
+    fn diagnostic() {
+        let error = parse::<u64>(INPUT, &INPUT[23..25])
+            .unwrap_err()
+            .with_file("input.txt");
+        assert_eq!(
+            error.diagnostic(),
+            "error: expected u64\n --> input.txt:2:13\n  |\n2 | Distance: 9 x0\n  |             ^^"
+        );
+    }
+
+    #[test]
+    fn missing_delimiter() {
+        let line = INPUT.lines().next().unwrap();
+        let error = split_once(INPUT, line, "|").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 11));
+    }
+}